@@ -9,6 +9,9 @@
 #![warn(missing_docs, rust_2018_idioms)]
 
 pub use digest::{self, KeyInit, Mac};
+pub use mac_common::MacExt;
+
+use mac_common::Truncated;
 
 use belt_block::BeltBlock;
 use cipher::{BlockCipherEncBackend, BlockCipherEncClosure, BlockCipherEncrypt};
@@ -28,11 +31,19 @@ use digest::{
 };
 
 #[cfg(feature = "zeroize")]
-use cipher::zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Generic BeltMac instance.
 pub type BeltMac<C = BeltBlock> = CoreWrapper<BeltMacCore<C>>;
 
+/// Generic BeltMac instance with output truncated to `T` bytes.
+///
+/// The tag is the leftmost `T` bytes of the full BeltMac output. STB 34.101.31
+/// uses 64-bit tags (i.e. `T` equal to `U8`). Tags shorter than 32 bits are
+/// not allowed, i.e. `T` must be in the range from 4 to the cipher block size
+/// (inclusive). Use of other values results in a compilation error.
+pub type BeltMacTruncated<T, C = BeltBlock> = Truncated<BeltMacCore<C>, T>;
+
 #[derive(Clone)]
 /// Generic core BeltMac instance, which operates over blocks.
 pub struct BeltMacCore<C = BeltBlock>
@@ -123,7 +134,12 @@ where
 {
     #[inline(always)]
     fn reset(&mut self) {
-        self.state = Default::default();
+        #[cfg(feature = "zeroize")]
+        self.state.zeroize();
+        #[cfg(not(feature = "zeroize"))]
+        {
+            self.state = Default::default();
+        }
     }
 }
 
@@ -0,0 +1,12 @@
+use belt_mac::{BeltMac, KeyInit, Mac, MacExt};
+
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+#[test]
+fn belt_mac_mac_oneshot() {
+    let key = [0x42; 32];
+    let tag = <BeltMac>::mac_oneshot(&key, DATA).unwrap();
+    let mac = <BeltMac>::new_from_slice(&key).unwrap();
+    mac.chain_update(DATA).verify(&tag).unwrap();
+    assert!(<BeltMac>::mac_oneshot(&key[1..], DATA).is_err());
+}
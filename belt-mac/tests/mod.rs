@@ -1,4 +1,8 @@
 use belt_mac::BeltMac;
 use digest::new_resettable_mac_test;
 
+// Test vectors from STB 34.101.31-2020, Appendix A (`belt-mac` examples).
+// The first vector covers a partial final block (13-byte message, `phi2`
+// transform) and the second one a message of three full blocks (`phi1`).
+// The standard specifies 64-bit tags, so the output is truncated to the left.
 new_resettable_mac_test!(belt_mac_stb, "belt-mac", BeltMac, "left");
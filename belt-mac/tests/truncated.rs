@@ -0,0 +1,43 @@
+use belt_mac::{
+    digest::{
+        consts::{U16, U4, U8},
+        new_resettable_mac_test,
+    },
+    BeltMac, BeltMacTruncated, KeyInit, Mac,
+};
+use hex_literal::hex;
+
+// STB 34.101.31 test vectors use 64-bit tags
+new_resettable_mac_test!(belt_mac_truncated_stb, "belt-mac", BeltMacTruncated<U8>);
+
+#[test]
+fn belt_mac_truncated_128() {
+    let key = [0x42; 32];
+    let msg = b"input message";
+
+    let mut mac = BeltMacTruncated::<U16>::new_from_slice(&key).unwrap();
+    mac.update(msg);
+    let tag = mac.finalize_reset().into_bytes();
+    assert_eq!(tag, hex!("9f5c9623b4eff8802195e81bcd841959"));
+
+    let full = <BeltMac>::new_from_slice(&key)
+        .unwrap()
+        .chain_update(msg)
+        .finalize()
+        .into_bytes();
+    assert_eq!(tag, full);
+
+    // reset must return the instance to its freshly keyed state
+    mac.update(msg);
+    mac.verify(&tag).unwrap();
+}
+
+#[test]
+fn belt_mac_truncated_is_prefix() {
+    let key = [0x42; 32];
+    let msg = b"input message";
+
+    let mac = BeltMacTruncated::<U4>::new_from_slice(&key).unwrap();
+    let tag = mac.chain_update(msg).finalize().into_bytes();
+    assert_eq!(tag, hex!("9f5c9623"));
+}
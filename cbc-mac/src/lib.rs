@@ -22,6 +22,12 @@
 //! mac.verify_slice(&correct).unwrap();
 //! ```
 //!
+//! # Key length
+//! The key is used directly as the block cipher key, so only keys with
+//! length equal to the cipher key size are accepted (e.g. 8 bytes for DES).
+//! `new_from_slice` returns [`InvalidLength`][digest::InvalidLength] for keys
+//! of any other length, including empty keys.
+//!
 //! [CBC-MAC]: https://en.wikipedia.org/wiki/CBC-MAC#Security_with_fixed_and_variable-length_messages
 
 #![no_std]
@@ -34,6 +40,7 @@
 #![warn(missing_docs, rust_2018_idioms)]
 
 pub use digest::{self, KeyInit, Mac};
+pub use mac_common::MacExt;
 
 use cipher::{BlockCipherEncBackend, BlockCipherEncClosure, BlockCipherEncrypt};
 use core::fmt;
@@ -52,7 +59,7 @@ use digest::{
 };
 
 #[cfg(feature = "zeroize")]
-use cipher::zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Generic CMAC instance.
 pub type CbcMac<C> = CoreWrapper<CbcMacCore<C>>;
@@ -144,7 +151,12 @@ where
 {
     #[inline(always)]
     fn reset(&mut self) {
-        self.state = Default::default();
+        #[cfg(feature = "zeroize")]
+        self.state.zeroize();
+        #[cfg(not(feature = "zeroize"))]
+        {
+            self.state = Default::default();
+        }
     }
 }
 
@@ -0,0 +1,13 @@
+use aes::Aes128;
+use cbc_mac::{CbcMac, KeyInit, Mac, MacExt};
+
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+#[test]
+fn cbc_mac_mac_oneshot() {
+    let key = [0x42; 16];
+    let tag = CbcMac::<Aes128>::mac_oneshot(&key, DATA).unwrap();
+    let mac = CbcMac::<Aes128>::new_from_slice(&key).unwrap();
+    mac.chain_update(DATA).verify(&tag).unwrap();
+    assert!(CbcMac::<Aes128>::mac_oneshot(&key[1..], DATA).is_err());
+}
@@ -0,0 +1,35 @@
+//! CBC-MAC is not secure for variable-length messages: knowing tags of
+//! two one-block messages allows to forge tag of a two-block message.
+use aes::Aes128;
+use cbc_mac::{digest::KeyInit, CbcMac, Mac};
+
+type CbcMacAes128 = CbcMac<Aes128>;
+
+fn tag(msg: &[u8]) -> [u8; 16] {
+    CbcMacAes128::new_from_slice(b"very secret key.")
+        .unwrap()
+        .chain_update(msg)
+        .finalize()
+        .into_bytes()
+        .into()
+}
+
+#[test]
+fn cbc_mac_length_extension_forgery() {
+    let m1 = *b"first message 01";
+    let m2 = *b"second message 2";
+    let t1 = tag(&m1);
+    let t2 = tag(&m2);
+
+    // CBC-MAC(m1 || (m2 ^ t1)) == CBC-MAC(m2)
+    let mut forged = [0u8; 32];
+    forged[..16].copy_from_slice(&m1);
+    for i in 0..16 {
+        forged[16 + i] = m2[i] ^ t1[i];
+    }
+
+    let mac = CbcMacAes128::new_from_slice(b"very secret key.")
+        .unwrap()
+        .chain_update(forged);
+    mac.verify_slice(&t2).unwrap();
+}
@@ -0,0 +1,260 @@
+use alloc::boxed::Box;
+use cipher::BlockCipherEncrypt;
+use core::{cmp::min, fmt};
+use dbl::Dbl;
+use digest::{array::typenum::Unsigned, core_api::Block, InvalidLength, KeyInit, MacError, Update};
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Maximum block size supported by [`DynCmac`] in bytes.
+const MAX_BLOCK_SIZE: usize = 32;
+
+/// Object safe block encryption trait used by [`DynCmac`].
+trait DynEncrypt {
+    /// Encrypt block in-place, `block` length must be equal to cipher block size.
+    fn encrypt(&self, block: &mut [u8]);
+
+    fn clone_box(&self) -> Box<dyn DynEncrypt>;
+}
+
+impl<C: BlockCipherEncrypt + Clone + 'static> DynEncrypt for C {
+    #[inline]
+    fn encrypt(&self, block: &mut [u8]) {
+        let block: &mut Block<C> = block.try_into().expect("block has correct length");
+        self.encrypt_block(block);
+    }
+
+    fn clone_box(&self) -> Box<dyn DynEncrypt> {
+        Box::new(self.clone())
+    }
+}
+
+/// CMAC instance over a block cipher selected at runtime.
+///
+/// Unlike [`Cmac`][crate::Cmac], this type is not generic over the block
+/// cipher, which makes it possible to select the cipher at runtime (e.g.
+/// based on protocol negotiation) and to store CMACs over different
+/// ciphers in one collection. The cipher is stored in a [`Box`] and
+/// accessed through dynamic dispatch.
+///
+/// Since output size of `DynCmac` is known only at runtime, it does not
+/// implement the [`Mac`][crate::Mac] trait. Tags are returned as boxed
+/// slices with length equal to the cipher block size.
+///
+/// # Examples
+///
+/// ```rust
+/// use aes::{Aes128, Aes256};
+/// use cmac::DynCmac;
+///
+/// fn new_cmac(key: &[u8]) -> DynCmac {
+///     match key.len() {
+///         16 => DynCmac::new_from_slice::<Aes128>(key).unwrap(),
+///         32 => DynCmac::new_from_slice::<Aes256>(key).unwrap(),
+///         _ => panic!("unsupported key length"),
+///     }
+/// }
+///
+/// let mut mac = new_cmac(b"very secret key.");
+/// mac.update(b"input message");
+/// let tag = mac.finalize();
+/// assert_eq!(tag.len(), 16);
+/// ```
+pub struct DynCmac {
+    cipher: Box<dyn DynEncrypt>,
+    block_size: usize,
+    key1: [u8; MAX_BLOCK_SIZE],
+    key2: [u8; MAX_BLOCK_SIZE],
+    state: [u8; MAX_BLOCK_SIZE],
+    buffer: [u8; MAX_BLOCK_SIZE],
+    pos: usize,
+}
+
+impl DynCmac {
+    /// Create new CMAC instance from initialized block cipher.
+    pub fn new<C>(cipher: C) -> Self
+    where
+        C: BlockCipherEncrypt + Clone + 'static,
+        Block<C>: Dbl,
+    {
+        let block_size = C::BlockSize::USIZE;
+        const {
+            assert!(
+                C::BlockSize::USIZE <= MAX_BLOCK_SIZE,
+                "cipher block size is not supported",
+            );
+        }
+
+        let mut subkey = Block::<C>::default();
+        cipher.encrypt_block(&mut subkey);
+        let k1 = subkey.dbl();
+        let k2 = k1.clone().dbl();
+
+        let mut key1 = [0u8; MAX_BLOCK_SIZE];
+        let mut key2 = [0u8; MAX_BLOCK_SIZE];
+        key1[..block_size].copy_from_slice(&k1);
+        key2[..block_size].copy_from_slice(&k2);
+
+        Self {
+            cipher: Box::new(cipher),
+            block_size,
+            key1,
+            key2,
+            state: [0u8; MAX_BLOCK_SIZE],
+            buffer: [0u8; MAX_BLOCK_SIZE],
+            pos: 0,
+        }
+    }
+
+    /// Create new CMAC instance using block cipher `C` initialized with `key`.
+    pub fn new_from_slice<C>(key: &[u8]) -> Result<Self, InvalidLength>
+    where
+        C: BlockCipherEncrypt + KeyInit + Clone + 'static,
+        Block<C>: Dbl,
+    {
+        C::new_from_slice(key).map(Self::new)
+    }
+
+    /// Block size of the underlying cipher, which is equal to the tag length.
+    #[inline]
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Update state using the provided data.
+    pub fn update(&mut self, mut data: &[u8]) {
+        let bs = self.block_size;
+        while !data.is_empty() {
+            // The last block gets special treatment in `finalize`,
+            // so full buffer is processed only after more data arrives
+            if self.pos == bs {
+                xor(&mut self.state[..bs], &self.buffer[..bs]);
+                self.cipher.encrypt(&mut self.state[..bs]);
+                self.pos = 0;
+            }
+            let n = min(bs - self.pos, data.len());
+            self.buffer[self.pos..][..n].copy_from_slice(&data[..n]);
+            self.pos += n;
+            data = &data[n..];
+        }
+    }
+
+    /// Obtain the tag and consume the CMAC instance.
+    pub fn finalize(mut self) -> Box<[u8]> {
+        self.finalize_reset()
+    }
+
+    /// Obtain the tag and reset the CMAC instance.
+    pub fn finalize_reset(&mut self) -> Box<[u8]> {
+        let bs = self.block_size;
+        let mut block = [0u8; MAX_BLOCK_SIZE];
+        block[..self.pos].copy_from_slice(&self.buffer[..self.pos]);
+        let subkey = if self.pos == bs {
+            &self.key1
+        } else {
+            block[self.pos] = 0x80;
+            &self.key2
+        };
+        xor(&mut self.state[..bs], &block[..bs]);
+        xor(&mut self.state[..bs], &subkey[..bs]);
+        self.cipher.encrypt(&mut self.state[..bs]);
+
+        let tag = Box::from(&self.state[..bs]);
+        #[cfg(feature = "zeroize")]
+        block.zeroize();
+        self.reset();
+        tag
+    }
+
+    /// Check if tag is correct for the processed input.
+    ///
+    /// Tag comparison is performed in constant time.
+    pub fn verify(self, tag: &[u8]) -> Result<(), MacError> {
+        let calc = self.finalize();
+        if calc.ct_eq(tag).into() {
+            Ok(())
+        } else {
+            Err(MacError)
+        }
+    }
+
+    /// Reset state to its initial value.
+    pub fn reset(&mut self) {
+        #[cfg(feature = "zeroize")]
+        {
+            self.state.zeroize();
+            self.buffer.zeroize();
+        }
+        #[cfg(not(feature = "zeroize"))]
+        {
+            self.state = [0u8; MAX_BLOCK_SIZE];
+            self.buffer = [0u8; MAX_BLOCK_SIZE];
+        }
+        self.pos = 0;
+    }
+}
+
+impl Update for DynCmac {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        DynCmac::update(self, data);
+    }
+}
+
+impl Clone for DynCmac {
+    fn clone(&self) -> Self {
+        Self {
+            cipher: self.cipher.clone_box(),
+            block_size: self.block_size,
+            key1: self.key1,
+            key2: self.key2,
+            state: self.state,
+            buffer: self.buffer,
+            pos: self.pos,
+        }
+    }
+}
+
+impl fmt::Debug for DynCmac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DynCmac { ... }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl Drop for DynCmac {
+    fn drop(&mut self) {
+        self.key1.zeroize();
+        self.key2.zeroize();
+        self.state.zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+#[inline(always)]
+fn xor(buf: &mut [u8], data: &[u8]) {
+    for (a, b) in buf.iter_mut().zip(data) {
+        *a ^= b;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aes::Aes128;
+
+    #[test]
+    fn reset_zeroizes_state() {
+        let mut mac = DynCmac::new_from_slice::<Aes128>(&[0x42; 16]).unwrap();
+        mac.update(&[0xA5; 20]);
+        assert!(mac.state.iter().any(|&b| b != 0));
+        assert!(mac.buffer.iter().any(|&b| b != 0));
+        mac.reset();
+        assert!(mac.state.iter().all(|&b| b == 0));
+        assert!(mac.buffer.iter().all(|&b| b == 0));
+        assert_eq!(mac.pos, 0);
+    }
+}
@@ -0,0 +1,103 @@
+//! Key derivation function in counter mode with CMAC as PRF as defined in
+//! [NIST SP 800-108][1].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use aes::Aes128;
+//! use cmac::kdf::kdf_ctr;
+//!
+//! let mut derived_key = [0u8; 32];
+//! kdf_ctr::<Aes128>(b"very secret key.", b"label", b"context", &mut derived_key).unwrap();
+//! ```
+//!
+//! [1]: https://csrc.nist.gov/pubs/sp/800/108/r1/upd1/final
+
+use crate::Cmac;
+use cipher::{BlockCipherEncrypt, KeyInit};
+use dbl::Dbl;
+use digest::{
+    array::typenum::{IsLess, Le, NonZero, Unsigned, U256},
+    core_api::Block,
+    InvalidLength, Mac,
+};
+
+/// Fill `out` with key material derived from `key` using KDF in counter mode
+/// with CMAC as PRF.
+///
+/// Every block of output is computed as
+/// `CMAC(key, [i]_2 || label || 0x00 || context || [L]_2)`, where the block
+/// counter `i` (starting from 1) and the output length in bits `L` are
+/// encoded as 32-bit big-endian integers.
+///
+/// Returns [`InvalidLength`] if `key` has invalid length for the cipher `C`
+/// or if length of `out` in bits does not fit into `u32`.
+pub fn kdf_ctr<C>(
+    key: &[u8],
+    label: &[u8],
+    context: &[u8],
+    out: &mut [u8],
+) -> Result<(), InvalidLength>
+where
+    C: BlockCipherEncrypt + KeyInit + Clone,
+    Block<C>: Dbl,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+{
+    let out_bits = out
+        .len()
+        .checked_mul(8)
+        .and_then(|l| u32::try_from(l).ok())
+        .ok_or(InvalidLength)?
+        .to_be_bytes();
+    kdf_ctr_parts::<C>(key, &[label, &[0u8], context, &out_bits], out)
+}
+
+/// Fill `out` with key material derived from `key` using KDF in counter mode
+/// with CMAC as PRF and caller-provided fixed input data.
+///
+/// Every block of output is computed as `CMAC(key, [i]_2 || fixed_input)`,
+/// where the block counter `i` (starting from 1) is encoded as a 32-bit
+/// big-endian integer. Encoding of the label, context, and output length
+/// into `fixed_input` is the caller's responsibility.
+///
+/// Returns [`InvalidLength`] if `key` has invalid length for the cipher `C`
+/// or if `out` requires more than `2^32 - 1` blocks.
+pub fn kdf_ctr_with_fixed_input<C>(
+    key: &[u8],
+    fixed_input: &[u8],
+    out: &mut [u8],
+) -> Result<(), InvalidLength>
+where
+    C: BlockCipherEncrypt + KeyInit + Clone,
+    Block<C>: Dbl,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+{
+    kdf_ctr_parts::<C>(key, &[fixed_input], out)
+}
+
+/// KDF in counter mode with fixed input data split into several parts.
+fn kdf_ctr_parts<C>(key: &[u8], fixed_input: &[&[u8]], out: &mut [u8]) -> Result<(), InvalidLength>
+where
+    C: BlockCipherEncrypt + KeyInit + Clone,
+    Block<C>: Dbl,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+{
+    let blocks = out.len().div_ceil(C::BlockSize::USIZE);
+    if u32::try_from(blocks).is_err() {
+        return Err(InvalidLength);
+    }
+    let prf = Cmac::<C>::new_from_slice(key)?;
+
+    for (i, chunk) in (1u32..).zip(out.chunks_mut(C::BlockSize::USIZE)) {
+        let mut mac = prf.clone().chain_update(i.to_be_bytes());
+        for part in fixed_input {
+            mac.update(part);
+        }
+        let tag = mac.finalize().into_bytes();
+        chunk.copy_from_slice(&tag[..chunk.len()]);
+    }
+    Ok(())
+}
@@ -37,6 +37,29 @@
 //! mac.verify(&tag_bytes).unwrap();
 //! ```
 //!
+//! Truncated tags as allowed by NIST SP 800-38B are supported by the
+//! [`CmacTruncated`] type:
+//!
+//! ```rust
+//! use aes::Aes128;
+//! use cmac::{digest::{consts::U8, KeyInit}, CmacTruncated, Mac};
+//!
+//! // CMAC-AES128 with 64-bit tags
+//! let mut mac = CmacTruncated::<Aes128, U8>::new_from_slice(b"very secret key.").unwrap();
+//! mac.update(b"input message");
+//! let tag_bytes = mac.finalize().into_bytes();
+//! assert_eq!(tag_bytes.len(), 8);
+//! ```
+//!
+//! # Key length
+//! The key is used directly as the block cipher key, so only keys with
+//! length equal to the cipher key size are accepted (e.g. 16 bytes for
+//! AES-128). `new_from_slice` returns [`InvalidLength`][digest::InvalidLength]
+//! for keys of any other length, including empty keys.
+//!
+//! CMAC over a block cipher selected at runtime is available as `DynCmac`
+//! with enabled `alloc` feature.
+//!
 //! [1]: https://en.wikipedia.org/wiki/One-key_MAC
 
 #![no_std]
@@ -48,14 +71,20 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![warn(missing_docs, rust_2018_idioms)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub use digest::{self, KeyInit, Mac};
+pub use mac_common::MacExt;
+
+use mac_common::Truncated;
 
 use cipher::{BlockCipherEncBackend, BlockCipherEncClosure, BlockCipherEncrypt};
 use core::fmt;
 use dbl::Dbl;
 use digest::{
     array::{
-        typenum::{IsLess, Le, NonZero, U256},
+        typenum::{IsLess, Le, NonZero, U256, U8},
         Array, ArraySize,
     },
     block_buffer::Lazy,
@@ -68,11 +97,28 @@ use digest::{
 };
 
 #[cfg(feature = "zeroize")]
-use cipher::zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "alloc")]
+mod dynamic;
+pub mod kdf;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use dynamic::DynCmac;
 
 /// Generic CMAC instance.
 pub type Cmac<C> = CoreWrapper<CmacCore<C>>;
 
+/// Generic CMAC instance with output truncated to `T` bytes.
+///
+/// The tag is the leftmost `T` bytes of the full CMAC output as described
+/// in NIST SP 800-38B. Following the recommendation of the standard, tags
+/// shorter than 64 bits are not allowed, i.e. `T` must be in the range
+/// from 8 to the cipher block size (inclusive). Use of other values results
+/// in a compilation error.
+pub type CmacTruncated<C, T> = Truncated<CmacCore<C>, T, U8>;
+
 /// Generic core CMAC instance, which operates over blocks.
 #[derive(Clone)]
 pub struct CmacCore<C>
@@ -173,7 +219,12 @@ where
 {
     #[inline(always)]
     fn reset(&mut self) {
-        self.state = Default::default();
+        #[cfg(feature = "zeroize")]
+        self.state.zeroize();
+        #[cfg(not(feature = "zeroize"))]
+        {
+            self.state = Default::default();
+        }
     }
 }
 
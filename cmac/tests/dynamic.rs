@@ -0,0 +1,71 @@
+#![cfg(feature = "alloc")]
+
+use aes::{Aes128, Aes256};
+use cmac::{digest::KeyInit, Cmac, DynCmac, Mac};
+use des::TdesEde3;
+
+fn check<C>(key: &[u8])
+where
+    C: cipher::BlockCipherEncrypt + KeyInit + Clone + 'static,
+    Cmac<C>: Mac + KeyInit,
+    cmac::digest::core_api::Block<C>: dbl::Dbl,
+{
+    let data: Vec<u8> = (0..100u8).collect();
+    let mut dyn_mac = DynCmac::new_from_slice::<C>(key).unwrap();
+    for n in 0..data.len() {
+        let expected = Cmac::<C>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&data[..n])
+            .finalize()
+            .into_bytes();
+
+        // feed data in uneven chunks
+        for chunk in data[..n].chunks(7) {
+            dyn_mac.update(chunk);
+        }
+        let mac_clone = dyn_mac.clone();
+        assert_eq!(dyn_mac.finalize_reset()[..], expected[..]);
+        mac_clone.verify(&expected).unwrap();
+    }
+}
+
+#[test]
+fn dyn_cmac_aes128() {
+    check::<Aes128>(&[0x42; 16]);
+}
+
+#[test]
+fn dyn_cmac_aes256() {
+    check::<Aes256>(&[0x42; 32]);
+}
+
+#[test]
+fn dyn_cmac_tdes() {
+    check::<TdesEde3>(&[0x42; 24]);
+}
+
+#[test]
+fn dyn_cmac_runtime_selection() {
+    let keys: [&[u8]; 2] = [&[0x42; 16], &[0x42; 32]];
+    let macs: Vec<DynCmac> = keys
+        .iter()
+        .map(|key| match key.len() {
+            16 => DynCmac::new_from_slice::<Aes128>(key).unwrap(),
+            32 => DynCmac::new_from_slice::<Aes256>(key).unwrap(),
+            _ => unreachable!(),
+        })
+        .collect();
+    assert!(macs.iter().all(|mac| mac.block_size() == 16));
+
+    let mut mac = macs[1].clone();
+    mac.update(b"input message");
+    let mut bad = mac.clone().finalize();
+    bad[0] ^= 1;
+    assert!(mac.clone().verify(&bad).is_err());
+    assert!(mac.verify(&bad[..15]).is_err());
+}
+
+#[test]
+fn dyn_cmac_invalid_key() {
+    assert!(DynCmac::new_from_slice::<Aes128>(&[0x42; 15]).is_err());
+}
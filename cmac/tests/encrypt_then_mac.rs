@@ -0,0 +1,103 @@
+//! Reference encrypt-then-MAC construction using CMAC-AES128.
+//!
+//! The ciphertext is produced in place and then authenticated directly from
+//! the same buffer, i.e. no copy of the ciphertext is needed. On the
+//! receiving side the tag is verified *before* decryption, so unauthenticated
+//! ciphertext is never decrypted.
+//!
+//! AES-CTR is implemented here only to keep the example self-contained,
+//! real code should use the `ctr` crate or an AEAD mode instead.
+use aes::{cipher::BlockCipherEncrypt, Aes128};
+use cmac::{digest::KeyInit, Cmac, Mac};
+
+type CmacAes128 = Cmac<Aes128>;
+
+const ENC_KEY: [u8; 16] = *b"encryption key!!";
+const MAC_KEY: [u8; 16] = *b"independent key.";
+const NONCE: [u8; 12] = *b"unique nonce";
+const MSG: &[u8] = b"message which should be encrypted and authenticated";
+
+/// Apply AES-CTR keystream to `buf` in place.
+fn ctr_apply(key: &[u8; 16], nonce: &[u8; 12], buf: &mut [u8]) {
+    let cipher = Aes128::new_from_slice(key).unwrap();
+    for (i, chunk) in buf.chunks_mut(16).enumerate() {
+        let mut block = [0u8; 16];
+        block[..12].copy_from_slice(nonce);
+        block[12..].copy_from_slice(&(i as u32).to_be_bytes());
+        let mut block = block.into();
+        cipher.encrypt_block(&mut block);
+        for (b, k) in chunk.iter_mut().zip(block.iter()) {
+            *b ^= k;
+        }
+    }
+}
+
+/// MAC over `nonce || ciphertext`.
+fn compute_mac(nonce: &[u8; 12], ciphertext: &[u8]) -> CmacAes128 {
+    // The nonce has fixed length, so the encoding is unambiguous. With
+    // variable-length associated data its length must be authenticated too.
+    CmacAes128::new_from_slice(&MAC_KEY)
+        .unwrap()
+        .chain_update(nonce)
+        .chain_update(ciphertext)
+}
+
+/// Encrypt `buf` in place and return tag over the ciphertext.
+fn seal(nonce: &[u8; 12], buf: &mut [u8]) -> [u8; 16] {
+    // 1. encrypt
+    ctr_apply(&ENC_KEY, nonce, buf);
+    // 2. MAC the ciphertext which was just written into `buf`
+    compute_mac(nonce, buf).finalize().into_bytes().into()
+}
+
+/// Verify tag and decrypt `buf` in place only if it's valid.
+fn open(nonce: &[u8; 12], buf: &mut [u8], tag: &[u8; 16]) -> Result<(), cmac::digest::MacError> {
+    // 1. verify the ciphertext
+    compute_mac(nonce, buf).verify_slice(tag)?;
+    // 2. decrypt only authenticated data
+    ctr_apply(&ENC_KEY, nonce, buf);
+    Ok(())
+}
+
+#[test]
+fn encrypt_then_mac_roundtrip() {
+    let mut buf = MSG.to_vec();
+    let tag = seal(&NONCE, &mut buf);
+    assert_ne!(buf, MSG);
+
+    open(&NONCE, &mut buf, &tag).unwrap();
+    assert_eq!(buf, MSG);
+}
+
+#[test]
+fn encrypt_then_mac_authenticates_ciphertext() {
+    let mut buf = MSG.to_vec();
+    let tag = seal(&NONCE, &mut buf);
+
+    // tag is computed over the ciphertext, not over the plaintext
+    let mut ciphertext = MSG.to_vec();
+    ctr_apply(&ENC_KEY, &NONCE, &mut ciphertext);
+    compute_mac(&NONCE, &ciphertext).verify_slice(&tag).unwrap();
+    assert!(compute_mac(&NONCE, MSG).verify_slice(&tag).is_err());
+}
+
+#[test]
+fn encrypt_then_mac_rejects_tampering() {
+    let mut buf = MSG.to_vec();
+    let tag = seal(&NONCE, &mut buf);
+
+    let mut tampered = buf.clone();
+    tampered[3] ^= 0x01;
+    let before = tampered.clone();
+    assert!(open(&NONCE, &mut tampered, &tag).is_err());
+    // rejected ciphertext is left untouched, i.e. it's never decrypted
+    assert_eq!(tampered, before);
+
+    let mut other_nonce = NONCE;
+    other_nonce[0] ^= 0x01;
+    assert!(open(&other_nonce, &mut buf.clone(), &tag).is_err());
+
+    let mut bad_tag = tag;
+    bad_tag[15] ^= 0x80;
+    assert!(open(&NONCE, &mut buf, &bad_tag).is_err());
+}
@@ -0,0 +1,34 @@
+//! Unlike CBC-MAC, CMAC is secure for variable-length messages, so the
+//! classic CBC-MAC length extension forgery must not work against it.
+use aes::Aes128;
+use cmac::{digest::KeyInit, Cmac, Mac};
+
+type CmacAes128 = Cmac<Aes128>;
+
+fn tag(msg: &[u8]) -> [u8; 16] {
+    CmacAes128::new_from_slice(b"very secret key.")
+        .unwrap()
+        .chain_update(msg)
+        .finalize()
+        .into_bytes()
+        .into()
+}
+
+#[test]
+fn cmac_resists_length_extension() {
+    let m1 = *b"first message 01";
+    let m2 = *b"second message 2";
+    let t1 = tag(&m1);
+    let t2 = tag(&m2);
+
+    let mut forged = [0u8; 32];
+    forged[..16].copy_from_slice(&m1);
+    for i in 0..16 {
+        forged[16 + i] = m2[i] ^ t1[i];
+    }
+
+    let mac = CmacAes128::new_from_slice(b"very secret key.")
+        .unwrap()
+        .chain_update(forged);
+    assert!(mac.verify_slice(&t2).is_err());
+}
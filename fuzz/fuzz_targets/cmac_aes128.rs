@@ -0,0 +1,71 @@
+//! Compare `Cmac<Aes128>` against a straightforward implementation
+//! of NIST SP 800-38B, feeding data in arbitrary split patterns.
+#![no_main]
+
+use aes::{cipher::BlockCipherEncrypt, Aes128};
+use cmac::{digest::KeyInit, Cmac, Mac};
+use libfuzzer_sys::fuzz_target;
+
+fn dbl(block: [u8; 16]) -> [u8; 16] {
+    let x = u128::from_be_bytes(block);
+    let res = (x << 1) ^ if x >> 127 == 1 { 0x87 } else { 0 };
+    res.to_be_bytes()
+}
+
+fn reference(key: &[u8; 16], msg: &[u8]) -> [u8; 16] {
+    let cipher = Aes128::new(key.into());
+    let encrypt = |block: &mut [u8; 16]| cipher.encrypt_block(block.into());
+
+    let mut l = [0u8; 16];
+    encrypt(&mut l);
+    let k1 = dbl(l);
+    let k2 = dbl(k1);
+
+    let n = if msg.is_empty() {
+        1
+    } else {
+        msg.len().div_ceil(16)
+    };
+    let mut state = [0u8; 16];
+    for chunk in msg.chunks(16).take(n - 1) {
+        state.iter_mut().zip(chunk).for_each(|(s, m)| *s ^= m);
+        encrypt(&mut state);
+    }
+
+    let last = &msg[16 * (n - 1)..];
+    let mut block = [0u8; 16];
+    block[..last.len()].copy_from_slice(last);
+    let subkey = if last.len() == 16 {
+        k1
+    } else {
+        block[last.len()] = 0x80;
+        k2
+    };
+    for i in 0..16 {
+        state[i] ^= block[i] ^ subkey[i];
+    }
+    encrypt(&mut state);
+    state
+}
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() < 17 {
+        return;
+    }
+    let (key, data) = data.split_at(16);
+    let key: &[u8; 16] = key.try_into().unwrap();
+    let (seed, msg) = (data[0], &data[1..]);
+
+    let mut mac = Cmac::<Aes128>::new(key.into());
+    let mut rest = msg;
+    let mut step = seed as usize;
+    while !rest.is_empty() {
+        step = (step * 31 + 7) % 41;
+        let n = step.min(rest.len());
+        mac.update(&rest[..n]);
+        rest = &rest[n..];
+    }
+
+    let tag = mac.finalize().into_bytes();
+    assert_eq!(tag[..], reference(key, msg)[..]);
+});
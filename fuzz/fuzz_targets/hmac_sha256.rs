@@ -0,0 +1,60 @@
+//! Compare `Hmac<Sha256>` and `SimpleHmac<Sha256>` against a straightforward
+//! implementation of RFC 2104, feeding data in arbitrary split patterns.
+#![no_main]
+
+use hmac::{Hmac, KeyInit, Mac, SimpleHmac};
+use libfuzzer_sys::fuzz_target;
+use sha2::{Digest, Sha256};
+
+const BLOCK_SIZE: usize = 64;
+
+fn reference(key: &[u8], msg: &[u8]) -> [u8; 32] {
+    let mut k = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        k[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        k[..key.len()].copy_from_slice(key);
+    }
+
+    let ipad = k.map(|b| b ^ 0x36);
+    let opad = k.map(|b| b ^ 0x5C);
+    let inner = Sha256::new()
+        .chain_update(ipad)
+        .chain_update(msg)
+        .finalize();
+    let outer = Sha256::new()
+        .chain_update(opad)
+        .chain_update(inner)
+        .finalize();
+    outer.into()
+}
+
+fn split_update<M: Mac>(mac: &mut M, msg: &[u8], seed: u8) {
+    let mut rest = msg;
+    let mut step = seed as usize;
+    while !rest.is_empty() {
+        step = (step * 31 + 7) % 131;
+        let n = step.min(rest.len());
+        mac.update(&rest[..n]);
+        rest = &rest[n..];
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() < 2 {
+        return;
+    }
+    // The first byte selects the key length, the second one the split pattern
+    let key_len = (data[0] as usize).min(data.len() - 2);
+    let seed = data[1];
+    let (key, msg) = data[2..].split_at(key_len);
+    let expected = reference(key, msg);
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+    split_update(&mut mac, msg, seed);
+    assert_eq!(mac.finalize().into_bytes()[..], expected[..]);
+
+    let mut mac = SimpleHmac::<Sha256>::new_from_slice(key).unwrap();
+    split_update(&mut mac, msg, seed);
+    assert_eq!(mac.finalize().into_bytes()[..], expected[..]);
+});
@@ -0,0 +1,41 @@
+#![feature(test)]
+extern crate test;
+
+use hmac::{Hmac, HmacCore, KeyInit, Mac};
+use sha2::Sha256;
+use test::Bencher;
+
+const KEY: &[u8] = b"my secret and secure key";
+
+macro_rules! bench_short {
+    ($($general:ident $short:ident $n:expr;)*) => {
+        $(
+            #[bench]
+            fn $general(b: &mut Bencher) {
+                let mac = Hmac::<Sha256>::new_from_slice(KEY).unwrap();
+                let data = [0x42u8; $n];
+                b.iter(|| test::black_box(mac.clone().chain_update(&data).finalize()));
+                b.bytes = $n;
+            }
+
+            #[bench]
+            fn $short(b: &mut Bencher) {
+                let core = HmacCore::<Sha256>::new_from_slice(KEY).unwrap();
+                let data = [0x42u8; $n];
+                b.iter(|| test::black_box(core.mac_short(&data)));
+                b.bytes = $n;
+            }
+        )*
+    };
+}
+
+// 55 bytes is the longest message which fits into one padded SHA-256 block,
+// 63 and 64 bytes are around the block boundary.
+bench_short!(
+    hmac_sha256_8 hmac_sha256_short_8 8;
+    hmac_sha256_16 hmac_sha256_short_16 16;
+    hmac_sha256_32 hmac_sha256_short_32 32;
+    hmac_sha256_55 hmac_sha256_short_55 55;
+    hmac_sha256_63 hmac_sha256_short_63 63;
+    hmac_sha256_64 hmac_sha256_short_64 64;
+);
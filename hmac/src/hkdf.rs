@@ -0,0 +1,126 @@
+//! [HKDF] key derivation function and the TLS 1.3 [`HKDF-Expand-Label`][1].
+//!
+//! All functions are generic over the MAC used as PRF, which is normally
+//! an HMAC instance, e.g. `Hmac<Sha256>`. Any other MAC implementing
+//! [`Mac`] and [`KeyInit`] (e.g. `Cmac<Aes128>`) can be used as well, in
+//! which case `salt` and `prk` must be valid keys for it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use hex_literal::hex;
+//! use hmac::{hkdf, Hmac};
+//! use sha2::Sha256;
+//!
+//! type HmacSha256 = Hmac<Sha256>;
+//!
+//! // TLS 1.3 early secret with no PSK (RFC 8448)
+//! let early_secret = hkdf::extract::<HmacSha256>(&[], &[0u8; 32]).unwrap();
+//! assert_eq!(
+//!     early_secret[..],
+//!     hex!("33ad0a1c607ec03b09e6cd9893680ce210adf300aa1f2660e1b22e10f170f92a"),
+//! );
+//!
+//! let mut key = [0u8; 16];
+//! hkdf::expand_label::<HmacSha256>(&early_secret, "key", &[], &mut key).unwrap();
+//! ```
+//!
+//! [HKDF]: https://datatracker.ietf.org/doc/html/rfc5869
+//! [1]: https://datatracker.ietf.org/doc/html/rfc8446#section-7.1
+
+use digest::{typenum::Unsigned, InvalidLength, KeyInit, Mac, Output};
+
+/// Prefix prepended to all labels by [`expand_label`].
+const TLS13_LABEL_PREFIX: &[u8] = b"tls13 ";
+
+/// HKDF-Extract: compute pseudorandom key from `salt` and input keying
+/// material `ikm`.
+///
+/// For HMAC an empty `salt` is equivalent to the default salt defined by
+/// RFC 5869 (i.e. a string of zeros with length equal to hash output).
+#[inline]
+pub fn extract<M>(salt: &[u8], ikm: &[u8]) -> Result<Output<M>, InvalidLength>
+where
+    M: Mac + KeyInit,
+{
+    let mac = M::new_from_slice(salt)?.chain_update(ikm);
+    Ok(mac.finalize().into_bytes())
+}
+
+/// HKDF-Expand: fill `out` with output keying material derived from
+/// pseudorandom key `prk` and context string `info`.
+///
+/// Returns [`InvalidLength`] if `prk` is not a valid key for `M` or if length
+/// of `out` is bigger than `255 * M::OutputSize` bytes.
+#[inline]
+pub fn expand<M>(prk: &[u8], info: &[u8], out: &mut [u8]) -> Result<(), InvalidLength>
+where
+    M: Mac + KeyInit + Clone,
+{
+    expand_parts::<M>(prk, &[info], out)
+}
+
+/// TLS 1.3 HKDF-Expand-Label: fill `out` with output keying material derived
+/// from `secret` using `label` and `context` encoded as `HkdfLabel` structure.
+///
+/// The `"tls13 "` prefix is added to `label` by this function. Length of
+/// `out` is used as the `length` field of `HkdfLabel`.
+///
+/// Returns [`InvalidLength`] if prefixed `label` or `context` do not fit into
+/// 255 bytes, if length of `out` does not fit into `u16` or is bigger than
+/// `255 * M::OutputSize` bytes, or if `secret` is not a valid key for `M`.
+pub fn expand_label<M>(
+    secret: &[u8],
+    label: &str,
+    context: &[u8],
+    out: &mut [u8],
+) -> Result<(), InvalidLength>
+where
+    M: Mac + KeyInit + Clone,
+{
+    let out_len = u16::try_from(out.len())
+        .map_err(|_| InvalidLength)?
+        .to_be_bytes();
+    let label_len =
+        u8::try_from(TLS13_LABEL_PREFIX.len() + label.len()).map_err(|_| InvalidLength)?;
+    let context_len = u8::try_from(context.len()).map_err(|_| InvalidLength)?;
+
+    let info: [&[u8]; 6] = [
+        &out_len,
+        &[label_len],
+        TLS13_LABEL_PREFIX,
+        label.as_bytes(),
+        &[context_len],
+        context,
+    ];
+    expand_parts::<M>(secret, &info, out)
+}
+
+/// HKDF-Expand with `info` split into several parts.
+fn expand_parts<M>(prk: &[u8], info: &[&[u8]], out: &mut [u8]) -> Result<(), InvalidLength>
+where
+    M: Mac + KeyInit + Clone,
+{
+    let n = M::OutputSize::USIZE;
+    if out.len() > 255 * n {
+        return Err(InvalidLength);
+    }
+
+    let prf = M::new_from_slice(prk)?;
+    let mut prev: Option<Output<M>> = None;
+    for (i, chunk) in out.chunks_mut(n).enumerate() {
+        let mut mac = prf.clone();
+        if let Some(t) = &prev {
+            mac.update(t);
+        }
+        for part in info {
+            mac.update(part);
+        }
+        // `i` is smaller than 255 because of the output length check above
+        mac.update(&[i as u8 + 1]);
+        let t = mac.finalize().into_bytes();
+        chunk.copy_from_slice(&t[..chunk.len()]);
+        prev = Some(t);
+    }
+    Ok(())
+}
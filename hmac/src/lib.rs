@@ -71,11 +71,22 @@
 //! to remove potential panic. This is done by truncating hash output to the hash
 //! block size if needed.
 //!
+//! # Key length
+//! HMAC accepts keys of any length, including empty keys, so key
+//! initialization never fails. Note that keys shorter than the hash output
+//! size (and especially empty keys) weaken the resulting MAC, see
+//! [RFC 2104, section 3](https://www.rfc-editor.org/rfc/rfc2104#section-3).
+//! It is the caller's responsibility to reject such keys when needed.
+//!
 //! # Crate features
 //! - `std`: enables functionality dependent on `std` (e.g. implementation of
 //!   the [`Error`][std::error::Error] trait for error types)
 //! - `reset`: enables implementation of the [`Reset`][digest::Reset] trait
 //!   (note that it makes HMAC states bigger)
+//! - `pbkdf2`: enables the [`pbkdf2()`] key derivation function
+//! - `hkdf`: enables the [`hkdf`] module with the HKDF key derivation function
+//! - `rand_core`: enables the `KeyInit::generate_key_with_rng` method,
+//!   which generates keys with length equal to the hash block size
 //!
 //! [`digest`]: https://docs.rs/digest
 //! [`sha2`]: https://docs.rs/sha2
@@ -94,21 +105,42 @@
 extern crate std;
 
 pub use digest::{self, KeyInit, Mac};
+pub use mac_common::MacExt;
 
 use digest::{
     core_api::{Block, BlockSizeUser},
     Digest,
 };
 
+#[cfg(feature = "hkdf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hkdf")))]
+pub mod hkdf;
 mod optim;
+#[cfg(feature = "pbkdf2")]
+mod pbkdf2;
 mod simple;
 
-pub use optim::{EagerHash, Hmac, HmacCore};
+pub use optim::{key_id, EagerHash, Hmac, HmacCore, HmacKey};
+#[cfg(feature = "pbkdf2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pbkdf2")))]
+pub use pbkdf2::pbkdf2;
 pub use simple::SimpleHmac;
 
 const IPAD: u8 = 0x36;
 const OPAD: u8 = 0x5C;
 
+/// Domain separation constant used for computing key identifiers.
+///
+/// Its length must differ from the output size of the used hash function.
+const KEY_ID_LABEL: &[u8] = b"RustCrypto HMAC key identifier";
+
+fn truncate_key_id(tag: &[u8]) -> [u8; 8] {
+    let mut id = [0u8; 8];
+    let n = core::cmp::min(tag.len(), id.len());
+    id[..n].copy_from_slice(&tag[..n]);
+    id
+}
+
 fn get_der_key<D: Digest + BlockSizeUser>(key: &[u8]) -> Block<D> {
     let mut der_key = Block::<D>::default();
     // The key that HMAC processes must be the same as the block size of the
@@ -1,12 +1,17 @@
-use super::{get_der_key, IPAD, OPAD};
-use core::{fmt, slice};
+use super::{get_der_key, truncate_key_id, IPAD, KEY_ID_LABEL, OPAD};
+use core::{fmt, ops::Add, slice};
 use digest::{
+    array::ArraySize,
     block_buffer::Eager,
     core_api::{
         AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, FixedOutputCore,
         OutputSizeUser, UpdateCore,
     },
-    crypto_common::{Key, KeySizeUser},
+    crypto_common::{
+        hazmat::{DeserializeStateError, SerializableState, SerializedState},
+        Key, KeySizeUser,
+    },
+    typenum::{Sum, Unsigned},
     HashMarker, InvalidLength, KeyInit, MacMarker, Output,
 };
 
@@ -55,6 +60,91 @@ impl<D: EagerHash> Clone for HmacCore<D> {
     }
 }
 
+impl<D: EagerHash> HmacCore<D> {
+    /// Compute a non-secret identifier of the key used by this instance.
+    ///
+    /// The identifier is the first 8 bytes of `H((K ^ opad) || label)`,
+    /// where `label` is a fixed domain separation constant. The hash input
+    /// differs in length from the input of the outer HMAC hash, so the
+    /// identifier is not a (truncated) HMAC tag of any message and does
+    /// not reveal the key. It can be used to select the right key during
+    /// verification. The result does not depend on data processed by
+    /// this instance.
+    ///
+    /// Note that the identifier is not a substitute for the MAC itself.
+    pub fn key_id(&self) -> [u8; 8] {
+        const {
+            assert!(KEY_ID_LABEL.len() != <D::Core as OutputSizeUser>::OutputSize::USIZE);
+        }
+        let mut h = self.opad_digest.clone();
+        let mut buffer = Buffer::<Self>::default();
+        buffer.digest_blocks(KEY_ID_LABEL, |b| h.update_blocks(b));
+        let mut hash = Output::<D::Core>::default();
+        h.finalize_fixed_core(&mut buffer, &mut hash);
+        truncate_key_id(&hash)
+    }
+
+    /// Compute HMAC tag over `data` without using the buffered [`Hmac`] wrapper.
+    ///
+    /// Data shorter than the hash block size (e.g. cookies or tokens) is
+    /// padded and finalized directly from the keyed inner state, and the inner
+    /// hash is finalized the same way from the keyed outer state. Longer data
+    /// falls back to block-wise processing, so results are correct for data
+    /// of any length. The keyed state is left untouched, so the same instance
+    /// can be used for computing tags of many messages.
+    ///
+    /// Note that blocks passed earlier via [`UpdateCore`] are prepended
+    /// to `data`, so for computing `HMAC(key, data)` this method should be
+    /// called on a freshly keyed instance.
+    pub fn mac_short(&self, data: &[u8]) -> Output<Self> {
+        let mut hash = Output::<D::Core>::default();
+        finalize_from(self.digest.clone(), data, &mut hash);
+        let mut out = Output::<Self>::default();
+        finalize_from(self.opad_digest.clone(), &hash, &mut out);
+        out
+    }
+}
+
+/// Finalize `core` over `data`, skipping block processing if `data` fits
+/// into the final (partial) block.
+#[inline(always)]
+fn finalize_from<C>(mut core: C, data: &[u8], out: &mut Output<C>)
+where
+    C: UpdateCore + FixedOutputCore + BufferKindUser<BufferKind = Eager>,
+{
+    let mut buffer = match Buffer::<C>::try_new(data) {
+        Ok(buffer) => buffer,
+        Err(_) => {
+            let mut buffer = Buffer::<C>::default();
+            buffer.digest_blocks(data, |b| core.update_blocks(b));
+            buffer
+        }
+    };
+    core.finalize_fixed_core(&mut buffer, out);
+}
+
+/// Compute a non-secret identifier of `key` for [`Hmac<D>`].
+///
+/// The result is equal to [`HmacCore::key_id`] of the core initialized
+/// with `key`, i.e. it can be used with [`Hmac`] which does not provide
+/// access to its core.
+///
+/// # Examples
+///
+/// ```rust
+/// use hmac::{key_id, HmacCore, KeyInit};
+/// use sha2::Sha256;
+///
+/// let key = b"my secret and secure key";
+/// let core = HmacCore::<Sha256>::new_from_slice(key).unwrap();
+/// assert_eq!(key_id::<Sha256>(key), core.key_id());
+/// ```
+pub fn key_id<D: EagerHash>(key: &[u8]) -> [u8; 8] {
+    HmacCore::<D>::new_from_slice(key)
+        .expect("HMAC accepts keys of any size")
+        .key_id()
+}
+
 impl<D: EagerHash> MacMarker for HmacCore<D> {}
 
 impl<D: EagerHash> BufferKindUser for HmacCore<D> {
@@ -137,6 +227,54 @@ impl<D: EagerHash> digest::Reset for HmacCore<D> {
     }
 }
 
+type CoreStateSize<D> = <<D as EagerHash>::Core as SerializableState>::SerializedStateSize;
+
+#[cfg(not(feature = "reset"))]
+type HmacCoreSerializedStateSize<S> = Sum<S, S>;
+#[cfg(feature = "reset")]
+type HmacCoreSerializedStateSize<S> = Sum<Sum<S, S>, S>;
+
+/// Serialized state contains the inner and the outer hash states
+/// (and the initial inner hash state if the `reset` feature is enabled).
+///
+/// Note that serialized state contains key-dependent data and should
+/// be handled as a secret.
+impl<D: EagerHash> SerializableState for HmacCore<D>
+where
+    D::Core: SerializableState,
+    CoreStateSize<D>: Add<CoreStateSize<D>>,
+    Sum<CoreStateSize<D>, CoreStateSize<D>>: Add<CoreStateSize<D>>,
+    HmacCoreSerializedStateSize<CoreStateSize<D>>: ArraySize,
+{
+    type SerializedStateSize = HmacCoreSerializedStateSize<CoreStateSize<D>>;
+
+    fn serialize(&self) -> SerializedState<Self> {
+        let n = CoreStateSize::<D>::USIZE;
+        let mut res = SerializedState::<Self>::default();
+        res[..n].copy_from_slice(&self.digest.serialize());
+        res[n..2 * n].copy_from_slice(&self.opad_digest.serialize());
+        #[cfg(feature = "reset")]
+        res[2 * n..].copy_from_slice(&self.ipad_digest.serialize());
+        res
+    }
+
+    fn deserialize(
+        serialized_state: &SerializedState<Self>,
+    ) -> Result<Self, DeserializeStateError> {
+        let n = CoreStateSize::<D>::USIZE;
+        let deserialize_core = |state: &[u8]| -> Result<D::Core, DeserializeStateError> {
+            let state = state.try_into().map_err(|_| DeserializeStateError)?;
+            D::Core::deserialize(state)
+        };
+        Ok(Self {
+            digest: deserialize_core(&serialized_state[..n])?,
+            opad_digest: deserialize_core(&serialized_state[n..2 * n])?,
+            #[cfg(feature = "reset")]
+            ipad_digest: deserialize_core(&serialized_state[2 * n..])?,
+        })
+    }
+}
+
 impl<D: EagerHash> AlgorithmName for HmacCore<D>
 where
     D::Core: AlgorithmName,
@@ -158,3 +296,68 @@ where
         f.write_str("> { ... }")
     }
 }
+
+/// Precomputed key-dependent HMAC state.
+///
+/// The type stores the inner and outer hash states derived from the key.
+/// It's never updated with message data, so it can be shared between
+/// threads (e.g. using `Arc`) and used to cheaply create fresh [`Hmac`]
+/// instances without repeating the key setup.
+///
+/// # Examples
+///
+/// ```rust
+/// use hmac::{Hmac, HmacKey, KeyInit, Mac};
+/// use sha2::Sha256;
+///
+/// let key = HmacKey::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+/// let mut mac: Hmac<Sha256> = key.new_instance();
+/// mac.update(b"input message");
+/// let tag = mac.finalize();
+/// ```
+pub struct HmacKey<D: EagerHash> {
+    core: HmacCore<D>,
+}
+
+impl<D: EagerHash> HmacKey<D> {
+    /// Create new HMAC instance in its freshly keyed state.
+    #[inline]
+    pub fn new_instance(&self) -> Hmac<D> {
+        Hmac::from_core(self.core.clone())
+    }
+}
+
+impl<D: EagerHash> Clone for HmacKey<D> {
+    fn clone(&self) -> Self {
+        Self {
+            core: self.core.clone(),
+        }
+    }
+}
+
+impl<D: EagerHash> KeySizeUser for HmacKey<D> {
+    type KeySize = <HmacCore<D> as KeySizeUser>::KeySize;
+}
+
+impl<D: EagerHash> KeyInit for HmacKey<D> {
+    #[inline(always)]
+    fn new(key: &Key<Self>) -> Self {
+        Self::new_from_slice(key.as_slice()).unwrap()
+    }
+
+    #[inline(always)]
+    fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+        HmacCore::new_from_slice(key).map(|core| Self { core })
+    }
+}
+
+impl<D: EagerHash> fmt::Debug for HmacKey<D>
+where
+    D::Core: AlgorithmName,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HmacKey<")?;
+        <D::Core as AlgorithmName>::write_alg_name(f)?;
+        f.write_str("> { ... }")
+    }
+}
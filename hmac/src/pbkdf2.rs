@@ -0,0 +1,83 @@
+use digest::{typenum::Unsigned, InvalidLength, KeyInit, Mac};
+
+/// Derive key from `password` and `salt` using [PBKDF2][1] with `M` as PRF.
+///
+/// Usually `M` is an HMAC instance, e.g. `Hmac<Sha256>`. The PRF is keyed
+/// with `password` only once and each iteration works over a clone of the
+/// keyed state, so the per-iteration cost is two hash compression calls
+/// for the block-level [`Hmac`][crate::Hmac].
+///
+/// Returns [`InvalidLength`] if `password` has invalid length for `M`,
+/// if `rounds` is equal to 0 (RFC 8018 requires at least one iteration),
+/// or if length of `out` is bigger than `(2^32 - 1) * M::OutputSize` bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use hex_literal::hex;
+/// use hmac::{pbkdf2, Hmac};
+/// use sha2::Sha256;
+///
+/// let mut key = [0u8; 20];
+/// pbkdf2::<Hmac<Sha256>>(b"password", b"salt", 4096, &mut key).unwrap();
+/// assert_eq!(key, hex!("c5e478d59288c841aa530db6845c4c8d962893a0"));
+/// ```
+///
+/// [1]: https://datatracker.ietf.org/doc/html/rfc8018#section-5.2
+pub fn pbkdf2<M>(
+    password: &[u8],
+    salt: &[u8],
+    rounds: u32,
+    out: &mut [u8],
+) -> Result<(), InvalidLength>
+where
+    M: Mac + KeyInit + Clone,
+{
+    if rounds == 0 {
+        return Err(InvalidLength);
+    }
+    check_output_len::<M>(out.len())?;
+    let prf = M::new_from_slice(password)?;
+    for (idx, chunk) in (1u32..).zip(out.chunks_mut(M::OutputSize::USIZE)) {
+        let mut u = prf
+            .clone()
+            .chain_update(salt)
+            .chain_update(idx.to_be_bytes())
+            .finalize()
+            .into_bytes();
+        chunk.copy_from_slice(&u[..chunk.len()]);
+        for _ in 1..rounds {
+            u = prf.clone().chain_update(&u).finalize().into_bytes();
+            for (a, b) in chunk.iter_mut().zip(u.iter()) {
+                *a ^= b;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check that `len` does not exceed the maximum PBKDF2 output length
+/// of `(2^32 - 1) * M::OutputSize` bytes.
+fn check_output_len<M: Mac>(len: usize) -> Result<(), InvalidLength> {
+    let max_len = (u32::MAX as u64).saturating_mul(M::OutputSize::U64);
+    if len as u64 > max_len {
+        Err(InvalidLength)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_output_len;
+    use crate::Hmac;
+    use sha1::Sha1;
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn output_len_limit() {
+        let max_len = 20 * (u32::MAX as usize);
+        assert!(check_output_len::<Hmac<Sha1>>(max_len).is_ok());
+        assert!(check_output_len::<Hmac<Sha1>>(max_len + 1).is_err());
+    }
+}
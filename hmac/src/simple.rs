@@ -1,7 +1,8 @@
-use super::{get_der_key, IPAD, OPAD};
+use super::{get_der_key, truncate_key_id, IPAD, KEY_ID_LABEL, OPAD};
 use core::fmt;
 use digest::{
     crypto_common::{Block, BlockSizeUser, InvalidLength, Key, KeySizeUser},
+    typenum::Unsigned,
     Digest, FixedOutput, KeyInit, MacMarker, Output, OutputSizeUser, Update,
 };
 #[cfg(feature = "reset")]
@@ -18,10 +19,30 @@ pub struct SimpleHmac<D: Digest + BlockSizeUser> {
     ipad_key: Block<D>,
 }
 
+impl<D: Digest + BlockSizeUser> SimpleHmac<D> {
+    /// Compute a non-secret identifier of the key used by this instance.
+    ///
+    /// See [`HmacCore::key_id`][crate::HmacCore::key_id] for more information.
+    pub fn key_id(&self) -> [u8; 8] {
+        const {
+            assert!(KEY_ID_LABEL.len() != <D as OutputSizeUser>::OutputSize::USIZE);
+        }
+        let hash = D::new()
+            .chain_update(&self.opad_key)
+            .chain_update(KEY_ID_LABEL)
+            .finalize();
+        truncate_key_id(&hash)
+    }
+}
+
 impl<D: Digest + BlockSizeUser> KeySizeUser for SimpleHmac<D> {
     type KeySize = D::BlockSize;
 }
 
+impl<D: Digest + BlockSizeUser> BlockSizeUser for SimpleHmac<D> {
+    type BlockSize = D::BlockSize;
+}
+
 impl<D: Digest + BlockSizeUser> MacMarker for SimpleHmac<D> {}
 
 impl<D: Digest + BlockSizeUser> KeyInit for SimpleHmac<D> {
@@ -0,0 +1,19 @@
+use hmac::{Hmac, KeyInit, Mac, MacExt, SimpleHmac};
+use sha2::Sha256;
+
+const KEY: &[u8] = b"my secret and secure key";
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+#[test]
+fn hmac_mac_oneshot() {
+    let tag = Hmac::<Sha256>::mac_oneshot(KEY, DATA).unwrap();
+    let mac = Hmac::<Sha256>::new_from_slice(KEY).unwrap();
+    mac.chain_update(DATA).verify(&tag).unwrap();
+}
+
+#[test]
+fn simple_hmac_mac_oneshot() {
+    let tag = SimpleHmac::<Sha256>::mac_oneshot(KEY, DATA).unwrap();
+    let mac = SimpleHmac::<Sha256>::new_from_slice(KEY).unwrap();
+    mac.chain_update(DATA).verify(&tag).unwrap();
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "hkdf")]
+
+use hex_literal::hex;
+use hmac::{hkdf, Hmac, SimpleHmac};
+use sha2::{Digest, Sha256};
+
+type HmacSha256 = Hmac<Sha256>;
+
+// Test cases 1 and 3 from RFC 5869:
+// https://datatracker.ietf.org/doc/html/rfc5869#appendix-A
+#[test]
+fn hkdf_sha256_rfc5869() {
+    let ikm = [0x0b; 22];
+    let salt = hex!("000102030405060708090a0b0c");
+    let info = hex!("f0f1f2f3f4f5f6f7f8f9");
+
+    let prk = hkdf::extract::<HmacSha256>(&salt, &ikm).unwrap();
+    assert_eq!(
+        prk[..],
+        hex!("077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5"),
+    );
+    let mut okm = [0u8; 42];
+    hkdf::expand::<HmacSha256>(&prk, &info, &mut okm).unwrap();
+    assert_eq!(
+        okm,
+        hex!(
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf"
+            "34007208d5b887185865"
+        ),
+    );
+
+    let prk = hkdf::extract::<SimpleHmac<Sha256>>(&[], &ikm).unwrap();
+    assert_eq!(
+        prk[..],
+        hex!("19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04"),
+    );
+    hkdf::expand::<SimpleHmac<Sha256>>(&prk, &[], &mut okm).unwrap();
+    assert_eq!(
+        okm,
+        hex!(
+            "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d"
+            "9d201395faa4b61a96c8"
+        ),
+    );
+}
+
+// Key schedule of the "Simple 1-RTT Handshake" from RFC 8448:
+// https://datatracker.ietf.org/doc/html/rfc8448#section-3
+#[test]
+fn hkdf_expand_label_rfc8448() {
+    let early_secret = hkdf::extract::<HmacSha256>(&[], &[0u8; 32]).unwrap();
+    assert_eq!(
+        early_secret[..],
+        hex!("33ad0a1c607ec03b09e6cd9893680ce210adf300aa1f2660e1b22e10f170f92a"),
+    );
+
+    let mut derived = [0u8; 32];
+    let empty_hash = Sha256::digest(b"");
+    hkdf::expand_label::<HmacSha256>(&early_secret, "derived", &empty_hash, &mut derived).unwrap();
+    assert_eq!(
+        derived,
+        hex!("6f2615a108c702c5678f54fc9dbab69716c076189c48250cebeac3576c3611ba"),
+    );
+
+    let shared = hex!("8bd4054fb55b9d63fdfbacf9f04b9f0d35e6d63f537563efd46272900f89492d");
+    let handshake_secret = hkdf::extract::<HmacSha256>(&derived, &shared).unwrap();
+    assert_eq!(
+        handshake_secret[..],
+        hex!("1dc826e93606aa6fdc0aadc12f741b01046aa6b99f691ed221a9f0ca043fbeac"),
+    );
+
+    // Transcript hash of ClientHello and ServerHello
+    let hello_hash = hex!("860c06edc07858ee8e78f0e7428c58edd6b43f2ca3e6e95f02ed063cf0e1cad8");
+    let mut client_secret = [0u8; 32];
+    hkdf::expand_label::<HmacSha256>(
+        &handshake_secret,
+        "c hs traffic",
+        &hello_hash,
+        &mut client_secret,
+    )
+    .unwrap();
+    assert_eq!(
+        client_secret,
+        hex!("b3eddb126e067f35a780b3abf45e2d8f3b1a950738f52e9600746a0e27a55a21"),
+    );
+
+    let mut server_secret = [0u8; 32];
+    hkdf::expand_label::<HmacSha256>(
+        &handshake_secret,
+        "s hs traffic",
+        &hello_hash,
+        &mut server_secret,
+    )
+    .unwrap();
+    assert_eq!(
+        server_secret,
+        hex!("b67b7d690cc16c4e75e54213cb2d37b4e9c912bcded9105d42befd59d391ad38"),
+    );
+
+    let mut key = [0u8; 16];
+    hkdf::expand_label::<HmacSha256>(&server_secret, "key", &[], &mut key).unwrap();
+    assert_eq!(key, hex!("3fce516009c21727d0f2e4e86ee403bc"));
+
+    let mut iv = [0u8; 12];
+    hkdf::expand_label::<HmacSha256>(&server_secret, "iv", &[], &mut iv).unwrap();
+    assert_eq!(iv, hex!("5d313eb2671276ee13000b30"));
+}
+
+#[test]
+fn hkdf_invalid_lengths() {
+    let prk = [0x42; 32];
+
+    let mut okm = [0u8; 255 * 32 + 1];
+    assert!(hkdf::expand::<HmacSha256>(&prk, &[], &mut okm).is_err());
+    assert!(hkdf::expand::<HmacSha256>(&prk, &[], &mut okm[..255 * 32]).is_ok());
+
+    let mut out = [0u8; 32];
+    let long_label = core::str::from_utf8(&[b'a'; 250]).unwrap();
+    assert!(hkdf::expand_label::<HmacSha256>(&prk, long_label, &[], &mut out).is_err());
+    let label = &long_label[..249];
+    assert!(hkdf::expand_label::<HmacSha256>(&prk, label, &[], &mut out).is_ok());
+    assert!(hkdf::expand_label::<HmacSha256>(&prk, "key", &[0; 256], &mut out).is_err());
+}
@@ -0,0 +1,33 @@
+use hmac::{Hmac, HmacKey, KeyInit, Mac};
+use sha2::Sha256;
+use std::{sync::Arc, thread};
+
+const KEY: &[u8] = b"my secret and secure key";
+
+#[test]
+fn hmac_key_shared_between_threads() {
+    let key = Arc::new(HmacKey::<Sha256>::new_from_slice(KEY).unwrap());
+
+    let handles: Vec<_> = (0..8u8)
+        .map(|i| {
+            let key = Arc::clone(&key);
+            thread::spawn(move || {
+                let msg = [i; 100];
+                let mut mac = key.new_instance();
+                mac.update(&msg);
+                let tag = mac.finalize().into_bytes();
+
+                let expected = Hmac::<Sha256>::new_from_slice(KEY)
+                    .unwrap()
+                    .chain_update(msg)
+                    .finalize()
+                    .into_bytes();
+                assert_eq!(tag, expected);
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
@@ -0,0 +1,64 @@
+use hex_literal::hex;
+use hmac::{key_id, HmacCore, KeyInit, Mac, SimpleHmac};
+use sha2::Sha256;
+
+const KEY: &[u8] = b"my secret and secure key";
+
+#[test]
+fn key_id_sha256() {
+    let expected = hex!("d3ddb1e66078157a");
+
+    let core = HmacCore::<Sha256>::new_from_slice(KEY).unwrap();
+    assert_eq!(core.key_id(), expected);
+
+    let mut mac = SimpleHmac::<Sha256>::new_from_slice(KEY).unwrap();
+    assert_eq!(mac.key_id(), expected);
+    // processed data must not affect the identifier
+    mac.update(b"input message");
+    assert_eq!(mac.key_id(), expected);
+}
+
+#[test]
+fn key_id_hmac() {
+    // `Hmac` does not expose its core, so the identifier is computed from the key
+    let id = key_id::<Sha256>(KEY);
+    assert_eq!(id, hex!("d3ddb1e66078157a"));
+    assert_eq!(
+        id,
+        SimpleHmac::<Sha256>::new_from_slice(KEY).unwrap().key_id()
+    );
+    assert_ne!(id, key_id::<Sha256>(b"another key"));
+}
+
+#[test]
+fn key_id_same_key() {
+    let a = SimpleHmac::<Sha256>::new_from_slice(KEY).unwrap();
+    let b = SimpleHmac::<Sha256>::new_from_slice(KEY).unwrap();
+    assert_eq!(a.key_id(), b.key_id());
+
+    let a = HmacCore::<Sha256>::new_from_slice(KEY).unwrap();
+    let b = HmacCore::<Sha256>::new_from_slice(KEY).unwrap();
+    assert_eq!(a.key_id(), b.key_id());
+}
+
+#[test]
+fn key_id_different_keys() {
+    let a = SimpleHmac::<Sha256>::new_from_slice(b"first key").unwrap();
+    let b = SimpleHmac::<Sha256>::new_from_slice(b"second key").unwrap();
+    assert_ne!(a.key_id(), b.key_id());
+
+    let a = HmacCore::<Sha256>::new_from_slice(b"first key").unwrap();
+    let b = HmacCore::<Sha256>::new_from_slice(b"second key").unwrap();
+    assert_ne!(a.key_id(), b.key_id());
+}
+
+#[test]
+fn key_id_is_not_a_tag() {
+    let core = HmacCore::<Sha256>::new_from_slice(KEY).unwrap();
+    let tag = SimpleHmac::<Sha256>::new_from_slice(KEY)
+        .unwrap()
+        .chain_update(b"RustCrypto HMAC key identifier")
+        .finalize()
+        .into_bytes();
+    assert_ne!(core.key_id()[..], tag[..8]);
+}
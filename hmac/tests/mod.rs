@@ -16,6 +16,17 @@ fn test_debug_impls() {
     needs_debug::<SimpleHmac<Sha256>>();
 }
 
+#[test]
+fn test_block_size() {
+    use digest::{core_api::BlockSizeUser, typenum::Unsigned};
+
+    type HmacBs = <HmacCore<Sha256> as BlockSizeUser>::BlockSize;
+    type SimpleBs = <SimpleHmac<Sha256> as BlockSizeUser>::BlockSize;
+    const _: () = assert!(HmacBs::USIZE == SimpleBs::USIZE);
+    assert_eq!(SimpleBs::USIZE, 64);
+    assert_eq!(SimpleHmac::<Sha512>::block_size(), 128);
+}
+
 // Test vectors from RFC 2104, plus wiki test
 test!(hmac_md5_rfc2104, "md5", Hmac<md5::Md5>);
 test!(hmac_md5_rfc2104_simple, "md5", SimpleHmac<md5::Md5>);
@@ -0,0 +1,80 @@
+#![cfg(feature = "pbkdf2")]
+
+use hex_literal::hex;
+use hmac::{pbkdf2, Hmac, SimpleHmac};
+use sha1::Sha1;
+use sha2::Sha256;
+
+struct TestVector {
+    password: &'static [u8],
+    salt: &'static [u8],
+    rounds: u32,
+    expected: &'static [u8],
+}
+
+// Test vectors from RFC 6070 (excluding the 16777216 rounds vector):
+// https://datatracker.ietf.org/doc/html/rfc6070
+const SHA1_VECTORS: &[TestVector] = &[
+    TestVector {
+        password: b"password",
+        salt: b"salt",
+        rounds: 1,
+        expected: &hex!("0c60c80f961f0e71f3a9b524af6012062fe037a6"),
+    },
+    TestVector {
+        password: b"password",
+        salt: b"salt",
+        rounds: 2,
+        expected: &hex!("ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957"),
+    },
+    TestVector {
+        password: b"password",
+        salt: b"salt",
+        rounds: 4096,
+        expected: &hex!("4b007901b765489abead49d926f721d065a429c1"),
+    },
+    TestVector {
+        password: b"passwordPASSWORDpassword",
+        salt: b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+        rounds: 4096,
+        expected: &hex!("3d2eec4fe41c849b80c8d83662c0e44a8b291a964cf2f07038"),
+    },
+    TestVector {
+        password: b"pass\0word",
+        salt: b"sa\0lt",
+        rounds: 4096,
+        expected: &hex!("56fa6aa75548099dcc37d7f03425e0c3"),
+    },
+];
+
+#[test]
+fn pbkdf2_hmac_sha1_rfc6070() {
+    for tv in SHA1_VECTORS {
+        let mut out = [0u8; 25];
+        let out = &mut out[..tv.expected.len()];
+        pbkdf2::<Hmac<Sha1>>(tv.password, tv.salt, tv.rounds, out).unwrap();
+        assert_eq!(out, tv.expected);
+
+        out.fill(0);
+        pbkdf2::<SimpleHmac<Sha1>>(tv.password, tv.salt, tv.rounds, out).unwrap();
+        assert_eq!(out, tv.expected);
+    }
+}
+
+#[test]
+fn pbkdf2_hmac_sha256_multi_block() {
+    let mut out = [0u8; 40];
+    pbkdf2::<Hmac<Sha256>>(b"password", b"salt", 4096, &mut out).unwrap();
+    let expected = hex!(
+        "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a"
+        "f7ad98c1b458ce3f"
+    );
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn pbkdf2_zero_rounds() {
+    let mut out = [0u8; 20];
+    assert!(pbkdf2::<Hmac<Sha1>>(b"password", b"salt", 0, &mut out).is_err());
+    assert!(pbkdf2::<SimpleHmac<Sha1>>(b"password", b"salt", 0, &mut out).is_err());
+}
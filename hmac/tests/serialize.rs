@@ -0,0 +1,58 @@
+use digest::crypto_common::hazmat::SerializableState;
+use hmac::{Hmac, HmacCore, KeyInit, Mac};
+use sha2::{Sha256, Sha512};
+
+const KEY: &[u8] = b"my secret and secure key";
+
+fn check_checkpoint<M>()
+where
+    M: Mac + KeyInit + SerializableState,
+{
+    let data = [0x13; 300];
+    let expected = M::new_from_slice(KEY)
+        .unwrap()
+        .chain_update(data)
+        .finalize();
+
+    // checkpoint at various positions including block boundaries
+    for n in [0, 1, 63, 64, 65, 128, 150, 299, 300] {
+        let mac = M::new_from_slice(KEY).unwrap().chain_update(&data[..n]);
+        let state = mac.serialize();
+        drop(mac);
+
+        let mac = M::deserialize(&state).unwrap();
+        assert_eq!(mac.chain_update(&data[n..]).finalize(), expected);
+    }
+}
+
+#[test]
+fn hmac_sha256_checkpoint() {
+    check_checkpoint::<Hmac<Sha256>>();
+}
+
+#[test]
+fn hmac_sha512_checkpoint() {
+    check_checkpoint::<Hmac<Sha512>>();
+}
+
+#[test]
+fn hmac_core_serialization_roundtrip() {
+    let core = HmacCore::<Sha256>::new_from_slice(KEY).unwrap();
+    let state = core.serialize();
+    let restored = HmacCore::<Sha256>::deserialize(&state).unwrap();
+    assert_eq!(restored.serialize(), state);
+}
+
+#[test]
+fn hmac_core_serialized_len() {
+    // SHA-256 and SHA-512 core states are 41 and 81 bytes long. Serialized
+    // HMAC state contains the inner and the outer hash states, plus
+    // the initial inner hash state with enabled `reset` feature.
+    let n = if cfg!(feature = "reset") { 3 } else { 2 };
+
+    let core = HmacCore::<Sha256>::new_from_slice(KEY).unwrap();
+    assert_eq!(core.serialize().len(), n * 41);
+
+    let core = HmacCore::<Sha512>::new_from_slice(KEY).unwrap();
+    assert_eq!(core.serialize().len(), n * 81);
+}
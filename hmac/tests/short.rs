@@ -0,0 +1,31 @@
+use hmac::{EagerHash, Hmac, HmacCore, KeyInit, Mac};
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+
+fn check_mac_short<D: EagerHash>() {
+    let key = b"my secret and secure key";
+    let core = HmacCore::<D>::new_from_slice(key).unwrap();
+    let mac = Hmac::<D>::new_from_slice(key).unwrap();
+
+    // cover block boundaries of all tested hashes
+    let data = [0xA5u8; 300];
+    for n in 0..data.len() {
+        let expected = mac.clone().chain_update(&data[..n]).finalize();
+        assert_eq!(core.mac_short(&data[..n])[..], expected.into_bytes()[..]);
+    }
+}
+
+#[test]
+fn mac_short_sha1() {
+    check_mac_short::<Sha1>();
+}
+
+#[test]
+fn mac_short_sha256() {
+    check_mac_short::<Sha256>();
+}
+
+#[test]
+fn mac_short_sha512() {
+    check_mac_short::<Sha512>();
+}
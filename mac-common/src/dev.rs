@@ -0,0 +1,222 @@
+//! Development-related functionality
+//!
+//! Test vectors are stored in text files with one vector per line and
+//! hex-encoded fields separated by `:`, e.g. `key:input:tag`. Empty lines
+//! and lines starting with `#` are ignored, so vectors can be annotated
+//! with their source. Empty fields (e.g. empty messages) are allowed.
+//!
+//! ```text
+//! # NIST SP 800-38B, D.1 AES-128, Example 1
+//! 2b7e151628aed2a6abf7158809cf4f3c::bb1d6929e95937287fa37d129b756746
+//! ```
+
+use alloc::vec::Vec;
+use core::{fmt, iter::Enumerate, str::Lines};
+
+/// Iterator over test vectors with `N` hex-encoded fields.
+///
+/// The parser does not allocate and yields slices of the source string,
+/// which can be decoded using [`decode_hex`].
+#[derive(Clone, Debug)]
+pub struct HexVectors<'a, const N: usize> {
+    lines: Enumerate<Lines<'a>>,
+}
+
+impl<'a, const N: usize> HexVectors<'a, N> {
+    /// Create new iterator over test vectors stored in `data`.
+    pub fn new(data: &'a str) -> Self {
+        Self {
+            lines: data.lines().enumerate(),
+        }
+    }
+}
+
+impl<'a, const N: usize> Iterator for HexVectors<'a, N> {
+    type Item = Result<[&'a str; N], InvalidVector>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (i, line) in self.lines.by_ref() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let err = InvalidVector { line: i + 1 };
+
+            let mut res = [""; N];
+            let mut fields = line.split(':');
+            for field in res.iter_mut() {
+                match fields.next() {
+                    Some(f) if is_hex(f) => *field = f,
+                    _ => return Some(Err(err)),
+                }
+            }
+            if fields.next().is_some() {
+                return Some(Err(err));
+            }
+            return Some(Ok(res));
+        }
+        None
+    }
+}
+
+/// Decode hex string `hex` into `buf`.
+///
+/// Returns `None` if `hex` is not a valid hex string or if `buf` is too
+/// short for the decoded data.
+pub fn decode_hex<'b>(hex: &str, buf: &'b mut [u8]) -> Option<&'b [u8]> {
+    if !is_hex(hex) {
+        return None;
+    }
+    let buf = buf.get_mut(..hex.len() / 2)?;
+    for (b, pair) in buf.iter_mut().zip(hex.as_bytes().chunks_exact(2)) {
+        *b = (nibble(pair[0]) << 4) | nibble(pair[1]);
+    }
+    Some(buf)
+}
+
+/// Run `f` over all test vectors with `N` fields stored in `data`.
+///
+/// `f` returns description of the failed check or `None` on success.
+///
+/// # Panics
+/// If `data` contains an invalid vector or if `f` has failed.
+pub fn run_hex_tests<const N: usize>(
+    data: &str,
+    mut f: impl FnMut([&[u8]; N]) -> Option<&'static str>,
+) {
+    for (i, row) in HexVectors::<N>::new(data).enumerate() {
+        let row = row.unwrap();
+        let bufs = row.map(|hex| {
+            let mut buf = alloc::vec![0u8; hex.len() / 2];
+            decode_hex(hex, &mut buf).unwrap();
+            buf
+        });
+        let fields = bufs.each_ref().map(Vec::as_slice);
+        if let Some(desc) = f(fields) {
+            panic!(
+                "\n\
+                 Failed test №{}: {}\n\
+                 fields:\t{:?}\n",
+                i, desc, row,
+            );
+        }
+    }
+}
+
+/// Test vector parsing error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidVector {
+    line: usize,
+}
+
+impl InvalidVector {
+    /// Line number (starting from 1) of the invalid vector.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for InvalidVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid test vector at line {}", self.line)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidVector {}
+
+fn is_hex(s: &str) -> bool {
+    s.len() % 2 == 0 && s.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn nibble(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        _ => c - b'A' + 10,
+    }
+}
+
+/// Define MAC test using hex-encoded `key:input:tag` vectors stored in
+/// `data/<test_name>.txt`.
+#[macro_export]
+macro_rules! new_mac_hex_test {
+    ($name:ident, $test_name:expr, $mac:ty $(,)?) => {
+        #[test]
+        fn $name() {
+            use core::cmp::min;
+            use $crate::digest::{KeyInit, Mac};
+
+            fn run_test([key, input, tag]: [&[u8]; 3]) -> Option<&'static str> {
+                let mac0 = <$mac as KeyInit>::new_from_slice(key).unwrap();
+
+                let mut mac = mac0.clone();
+                mac.update(input);
+                if mac.finalize().into_bytes()[..] != *tag {
+                    return Some("whole message");
+                }
+
+                // test reading different chunk sizes
+                for chunk_size in 1..min(64, input.len()) {
+                    let mut mac = mac0.clone();
+                    for chunk in input.chunks(chunk_size) {
+                        mac.update(chunk);
+                    }
+                    if mac.verify_slice(tag).is_err() {
+                        return Some("chunked message");
+                    }
+                }
+
+                None
+            }
+
+            let data = include_str!(concat!("data/", $test_name, ".txt"));
+            $crate::dev::run_hex_tests(data, run_test);
+        }
+    };
+}
+
+/// Define resettable MAC test using hex-encoded `key:input:tag` vectors
+/// stored in `data/<test_name>.txt`.
+#[macro_export]
+macro_rules! new_resettable_mac_hex_test {
+    ($name:ident, $test_name:expr, $mac:ty $(,)?) => {
+        #[test]
+        fn $name() {
+            use core::cmp::min;
+            use $crate::digest::{KeyInit, Mac};
+
+            fn run_test([key, input, tag]: [&[u8]; 3]) -> Option<&'static str> {
+                let mac0 = <$mac as KeyInit>::new_from_slice(key).unwrap();
+
+                let mut mac = mac0.clone();
+                mac.update(input);
+                if mac.finalize_reset().into_bytes()[..] != *tag {
+                    return Some("whole message");
+                }
+
+                // test if reset worked correctly
+                mac.update(input);
+                if mac.verify_slice(tag).is_err() {
+                    return Some("after reset");
+                }
+
+                // test reading different chunk sizes
+                for chunk_size in 1..min(64, input.len()) {
+                    let mut mac = mac0.clone();
+                    for chunk in input.chunks(chunk_size) {
+                        mac.update(chunk);
+                    }
+                    if mac.verify_slice(tag).is_err() {
+                        return Some("chunked message");
+                    }
+                }
+
+                None
+            }
+
+            let data = include_str!(concat!("data/", $test_name, ".txt"));
+            $crate::dev::run_hex_tests(data, run_test);
+        }
+    };
+}
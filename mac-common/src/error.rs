@@ -0,0 +1,41 @@
+use core::fmt;
+use digest::MacError;
+
+/// MAC verification error with a static label describing the failed check.
+///
+/// The label is meant to identify the MAC or the protocol operation
+/// (e.g. `"record-mac"`) and must not contain secret data.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct LabeledMacError {
+    label: &'static str,
+}
+
+impl LabeledMacError {
+    /// Create new error with the given label.
+    #[inline]
+    pub const fn new(label: &'static str) -> Self {
+        Self { label }
+    }
+
+    /// Label of the failed check.
+    #[inline]
+    pub const fn label(&self) -> &'static str {
+        self.label
+    }
+}
+
+impl fmt::Display for LabeledMacError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.label, MacError)
+    }
+}
+
+impl From<LabeledMacError> for MacError {
+    #[inline]
+    fn from(_: LabeledMacError) -> MacError {
+        MacError
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LabeledMacError {}
@@ -0,0 +1,245 @@
+use crate::LabeledMacError;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::min;
+use digest::{
+    typenum::Unsigned, FixedOutput, InvalidBufferSize, InvalidLength, KeyInit, Mac, MacError,
+    Output,
+};
+use subtle::{ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<T: digest::Mac> Sealed for T {}
+}
+
+/// Extension trait with convenience methods implemented for all [`Mac`] types.
+///
+/// This trait is sealed and can not be implemented outside of this crate.
+/// It is re-exported by the MAC implementation crates (e.g. `hmac` or
+/// `cmac`), so it can be imported together with the MAC:
+///
+/// ```rust
+/// use hmac::{Hmac, KeyInit, Mac, MacExt};
+/// use sha2::Sha256;
+///
+/// let mac = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+/// let tag = mac.clone().chain_update(b"input message").finalize().into_bytes();
+/// mac.chain_update(b"input message")
+///     .verify_truncated_left(&tag[..16])
+///     .unwrap();
+/// ```
+pub trait MacExt: Mac + sealed::Sealed {
+    /// Compute tag of `data` under `key` in one call.
+    ///
+    /// Note that the returned value is a raw byte array, so comparing it
+    /// with `==` is not constant time. Use [`Mac::verify_slice`] or wrap it
+    /// into [`CtOutput`][digest::CtOutput] for tag verification.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hmac::{Hmac, MacExt};
+    /// use sha2::Sha256;
+    ///
+    /// let tag = Hmac::<Sha256>::mac_oneshot(b"my secret and secure key", b"input message")?;
+    /// assert_eq!(tag.len(), 32);
+    /// # Ok::<(), hmac::digest::InvalidLength>(())
+    /// ```
+    #[inline]
+    fn mac_oneshot(key: &[u8], data: &[u8]) -> Result<Output<Self>, InvalidLength>
+    where
+        Self: KeyInit,
+    {
+        let mac = Self::new_from_slice(key)?.chain_update(data);
+        Ok(mac.finalize().into_bytes())
+    }
+
+    /// Length of tags produced by this MAC in bytes.
+    ///
+    /// The output size of a MAC is public, so it's safe to use this value
+    /// for rejecting malformed tags before processing a (potentially large)
+    /// message, see [`MacExt::check_tag_len`].
+    #[inline(always)]
+    fn expected_tag_len() -> usize {
+        Self::OutputSize::USIZE
+    }
+
+    /// Check that `tag` has the length expected by [`Mac::verify_slice`].
+    ///
+    /// Returns [`MacError`] if length of `tag` is not equal to MAC's output.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hmac::{Hmac, KeyInit, Mac, MacExt};
+    /// use sha2::Sha256;
+    ///
+    /// type HmacSha256 = Hmac<Sha256>;
+    ///
+    /// let tag = [0u8; 16];
+    /// // Reject the tag without touching the message
+    /// assert!(HmacSha256::check_tag_len(&tag).is_err());
+    /// ```
+    #[inline]
+    fn check_tag_len(tag: &[u8]) -> Result<(), MacError> {
+        if tag.len() == Self::expected_tag_len() {
+            Ok(())
+        } else {
+            Err(MacError)
+        }
+    }
+
+    /// Write tag into the first [`OutputSize`][digest::OutputSizeUser]
+    /// bytes of `out`.
+    ///
+    /// The tag is written directly into `out` without an intermediate copy,
+    /// e.g. into the tag field of a larger frame buffer. Truncating MACs
+    /// write only the truncated tag. The remaining bytes of `out` are left
+    /// untouched.
+    ///
+    /// Returns [`InvalidBufferSize`] if `out` is shorter than MAC's output.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hmac::{Hmac, KeyInit, Mac, MacExt};
+    /// use sha2::Sha256;
+    ///
+    /// let mut frame = [0u8; 64];
+    /// let (payload, tag) = frame.split_at_mut(32);
+    /// payload.copy_from_slice(b"payload which must be protected.");
+    ///
+    /// let mac = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+    /// mac.chain_update(&*payload).finalize_into_slice(tag)?;
+    /// # Ok::<(), hmac::digest::InvalidBufferSize>(())
+    /// ```
+    #[inline]
+    fn finalize_into_slice(self, out: &mut [u8]) -> Result<(), InvalidBufferSize>
+    where
+        Self: FixedOutput,
+    {
+        let out = out
+            .get_mut(..Self::OutputSize::USIZE)
+            .ok_or(InvalidBufferSize)?;
+        let out = <&mut Output<Self>>::try_from(out).map_err(|_| InvalidBufferSize)?;
+        FixedOutput::finalize_into(self, out);
+        Ok(())
+    }
+
+    /// Append tag to `buf` and return the combined buffer.
+    ///
+    /// It's useful for building the `message || tag` wire format in one
+    /// move.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hmac::{Hmac, KeyInit, Mac, MacExt};
+    /// use sha2::Sha256;
+    ///
+    /// let msg = b"input message".to_vec();
+    /// let mac = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+    /// let frame = mac.chain_update(&msg).finalize_append(msg);
+    /// assert_eq!(frame.len(), 13 + 32);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn finalize_append(self, mut buf: Vec<u8>) -> Vec<u8> {
+        buf.extend_from_slice(&self.finalize().into_bytes());
+        buf
+    }
+
+    /// Check tag correctness like [`Mac::verify_slice`], but return error
+    /// labeled with `label` on failure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hmac::{Hmac, KeyInit, Mac, MacExt};
+    /// use sha2::Sha256;
+    ///
+    /// let mac = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+    /// let err = mac
+    ///     .chain_update(b"input message")
+    ///     .verify_slice_labeled(&[0u8; 32], "record-mac")
+    ///     .unwrap_err();
+    /// assert_eq!(err.to_string(), "record-mac: MAC tag mismatch");
+    /// ```
+    #[inline]
+    fn verify_slice_labeled(self, tag: &[u8], label: &'static str) -> Result<(), LabeledMacError> {
+        self.verify_slice(tag)
+            .map_err(|_| LabeledMacError::new(label))
+    }
+
+    /// Compute tag and check its equality with `expected` in constant time.
+    ///
+    /// Unlike [`Mac::verify_slice`], this method returns the computed tag
+    /// together with the verification result, e.g. for logging or auditing.
+    /// The tag is computed only once and the comparison does not
+    /// return early on the first mismatched byte.
+    ///
+    /// Note that the returned tag is a raw byte array and it should not be
+    /// used for further comparisons with `==`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hmac::{Hmac, KeyInit, Mac, MacExt};
+    /// use sha2::Sha256;
+    ///
+    /// let mac = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+    /// let (tag, res) = mac.chain_update(b"input message").verify_and_get(&[0u8; 32]);
+    /// assert!(res.is_err());
+    /// assert_eq!(tag.len(), 32);
+    /// ```
+    fn verify_and_get(self, expected: &[u8]) -> (Output<Self>, Result<(), MacError>) {
+        let tag = self.finalize().into_bytes();
+        // Length of tags is public, so `ct_eq` on slices of different
+        // lengths may return immediately
+        let res = if tag[..].ct_eq(expected).into() {
+            Ok(())
+        } else {
+            Err(MacError)
+        };
+        (tag, res)
+    }
+
+    /// Check truncated tag correctness using left side bytes
+    /// (i.e. `tag[..n]`) of calculated tag without early returns.
+    ///
+    /// Unlike [`Mac::verify_truncated_left`], this method always computes
+    /// the full tag and processes all [`OutputSize`][digest::OutputSizeUser]
+    /// bytes of it regardless of `tag` length. Validity of the length
+    /// and equality of the compared bytes are combined in constant time,
+    /// so timing does not reveal which of the checks has failed.
+    ///
+    /// Returns [`MacError`] if `tag` is not valid, empty, or longer than MAC's
+    /// output.
+    fn verify_truncated_ct(self, tag: &[u8]) -> Result<(), MacError> {
+        let calc = self.finalize().into_bytes();
+        let n = calc.len();
+        let tag_len = tag.len() as u64;
+
+        // Missing bytes are padded with zeros and masked out below
+        let mut expected = Output::<Self>::default();
+        let m = min(tag.len(), n);
+        expected[..m].copy_from_slice(&tag[..m]);
+
+        let mut choice = !tag_len.ct_eq(&0) & !tag_len.ct_gt(&(n as u64));
+        for (i, (a, b)) in calc.iter().zip(expected.iter()).enumerate() {
+            let used = (i as u64).ct_lt(&tag_len);
+            choice &= !used | a.ct_eq(b);
+        }
+
+        if choice.into() {
+            Ok(())
+        } else {
+            Err(MacError)
+        }
+    }
+}
+
+impl<T: Mac> MacExt for T {}
@@ -0,0 +1,58 @@
+//! Common traits shared by the RustCrypto Message Authentication Code crates.
+//!
+//! The [`MacExt`] extension trait is re-exported by each MAC crate of the
+//! [`RustCrypto/MACs`] repository (e.g. [`hmac`] or [`cmac`]), so it can be
+//! imported together with the MAC itself:
+//!
+//! ```rust
+//! use hmac::{Hmac, MacExt};
+//! use sha2::Sha256;
+//!
+//! let tag = Hmac::<Sha256>::mac_oneshot(b"my secret and secure key", b"input message")?;
+//! assert_eq!(tag.len(), 32);
+//! # Ok::<(), hmac::digest::InvalidLength>(())
+//! ```
+//!
+//! The crate also provides the [`Truncated`] wrapper, which is used by the
+//! MAC crates for defining instances with truncated tags (e.g. `CmacTruncated`).
+//!
+//! # Crate features
+//! - `alloc`: enables functionality which requires heap allocation
+//!   (e.g. [`MacExt::finalize_append`])
+//! - `dev`: enables the [`dev`] module with helpers for defining tests
+//!   over hex-encoded test vectors (implies `alloc`)
+//! - `std`: implements [`std::error::Error`] for the error types
+//!   (implies `alloc`)
+//! - `zeroize`: implements [`ZeroizeOnDrop`][digest::zeroize::ZeroizeOnDrop]
+//!   for [`TruncatedCore`] over cores which implement it
+//!
+//! [`RustCrypto/MACs`]: https://github.com/RustCrypto/MACs
+//! [`hmac`]: https://docs.rs/hmac
+//! [`cmac`]: https://docs.rs/cmac
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/media/26acc39f/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/media/26acc39f/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![warn(missing_docs, rust_2018_idioms)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use digest;
+
+#[cfg(feature = "dev")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+pub mod dev;
+mod error;
+mod ext;
+mod truncated;
+
+pub use error::LabeledMacError;
+pub use ext::MacExt;
+pub use truncated::{Truncated, TruncatedCore};
@@ -0,0 +1,159 @@
+use core::{fmt, marker::PhantomData};
+use digest::{
+    array::ArraySize,
+    core_api::{
+        AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, FixedOutputCore,
+        UpdateCore,
+    },
+    crypto_common::{Key, KeySizeUser},
+    typenum::{IsLess, Le, NonZero, Unsigned, U256, U4},
+    InvalidLength, KeyInit, MacMarker, Output, OutputSizeUser, Reset,
+};
+
+#[cfg(feature = "zeroize")]
+use digest::zeroize::ZeroizeOnDrop;
+
+/// MAC with output of the block-level core `C` truncated to `T` bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use hmac::HmacCore;
+/// use mac_common::{digest::consts::U16, Truncated};
+/// use hmac::{KeyInit, Mac};
+/// use sha2::Sha256;
+///
+/// // HMAC-SHA256 with 128-bit tags
+/// let mut mac = Truncated::<HmacCore<Sha256>, U16>::new_from_slice(b"key").unwrap();
+/// mac.update(b"input message");
+/// let tag_bytes = mac.finalize().into_bytes();
+/// assert_eq!(tag_bytes.len(), 16);
+/// ```
+pub type Truncated<C, T, Min = U4> = CoreWrapper<TruncatedCore<C, T, Min>>;
+
+/// Block-level MAC core with output truncated to `T` bytes.
+///
+/// The tag is the leftmost `T` bytes of the full output of `C`. `T` must
+/// be in the range from `Min` (32 bits by default) to the output size of
+/// `C` (inclusive). Use of other values results in a compilation error
+/// on initialization.
+pub struct TruncatedCore<C, T, Min = U4> {
+    core: C,
+    _pd: PhantomData<(T, Min)>,
+}
+
+impl<C, T, Min> TruncatedCore<C, T, Min>
+where
+    C: OutputSizeUser,
+    T: ArraySize,
+    Min: Unsigned,
+{
+    #[inline]
+    fn from_core(core: C) -> Self {
+        const {
+            assert!(T::USIZE >= Min::USIZE, "truncated tag is too short");
+            assert!(
+                T::USIZE <= C::OutputSize::USIZE,
+                "truncated tag can not be longer than MAC output",
+            );
+        }
+        Self {
+            core,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<C: Clone, T, Min> Clone for TruncatedCore<C, T, Min> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            core: self.core.clone(),
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<C: BlockSizeUser, T, Min> BlockSizeUser for TruncatedCore<C, T, Min> {
+    type BlockSize = C::BlockSize;
+}
+
+impl<C, T: ArraySize, Min> OutputSizeUser for TruncatedCore<C, T, Min> {
+    type OutputSize = T;
+}
+
+impl<C: KeySizeUser, T, Min> KeySizeUser for TruncatedCore<C, T, Min> {
+    type KeySize = C::KeySize;
+}
+
+impl<C: MacMarker, T, Min> MacMarker for TruncatedCore<C, T, Min> {}
+
+impl<C, T, Min> KeyInit for TruncatedCore<C, T, Min>
+where
+    C: KeyInit + OutputSizeUser,
+    T: ArraySize,
+    Min: Unsigned,
+{
+    #[inline]
+    fn new(key: &Key<Self>) -> Self {
+        Self::from_core(C::new(key))
+    }
+
+    #[inline]
+    fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+        C::new_from_slice(key).map(Self::from_core)
+    }
+}
+
+impl<C: BufferKindUser, T, Min> BufferKindUser for TruncatedCore<C, T, Min> {
+    type BufferKind = C::BufferKind;
+}
+
+impl<C: UpdateCore, T, Min> UpdateCore for TruncatedCore<C, T, Min> {
+    #[inline]
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        self.core.update_blocks(blocks);
+    }
+}
+
+impl<C: Reset, T, Min> Reset for TruncatedCore<C, T, Min> {
+    #[inline(always)]
+    fn reset(&mut self) {
+        self.core.reset();
+    }
+}
+
+impl<C, T, Min> FixedOutputCore for TruncatedCore<C, T, Min>
+where
+    C: FixedOutputCore,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+    T: ArraySize,
+{
+    #[inline]
+    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        let mut full_tag = Output::<C>::default();
+        self.core.finalize_fixed_core(buffer, &mut full_tag);
+        out.copy_from_slice(&full_tag[..T::USIZE]);
+    }
+}
+
+impl<C: AlgorithmName, T: ArraySize, Min> AlgorithmName for TruncatedCore<C, T, Min> {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Truncated<")?;
+        C::write_alg_name(f)?;
+        write!(f, ", {}>", T::USIZE)
+    }
+}
+
+impl<C: AlgorithmName, T: ArraySize, Min> fmt::Debug for TruncatedCore<C, T, Min> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TruncatedCore<")?;
+        C::write_alg_name(f)?;
+        write!(f, ", {}> {{ ... }}", T::USIZE)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<C: ZeroizeOnDrop, T, Min> ZeroizeOnDrop for TruncatedCore<C, T, Min> {}
@@ -0,0 +1,46 @@
+#![cfg(feature = "dev")]
+
+use mac_common::dev::{decode_hex, HexVectors};
+
+const DATA: &str = "
+# comment line
+00ff:AbCd:
+
+0102:03:0405
+";
+
+#[test]
+fn hex_vectors() {
+    let mut iter = HexVectors::<3>::new(DATA);
+    assert_eq!(iter.next(), Some(Ok(["00ff", "AbCd", ""])));
+    assert_eq!(iter.next(), Some(Ok(["0102", "03", "0405"])));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn hex_vectors_invalid() {
+    for data in ["00:11", "00:11:22:33", "0:11:22", "00:1g:22"] {
+        let err = HexVectors::<3>::new(data).next().unwrap().unwrap_err();
+        assert_eq!(err.line(), 1);
+    }
+
+    let err = HexVectors::<2>::new("00:11\n\n00")
+        .nth(1)
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(err.line(), 3);
+    assert_eq!(err.to_string(), "invalid test vector at line 3");
+}
+
+#[test]
+fn decode() {
+    let mut buf = [0u8; 4];
+    assert_eq!(
+        decode_hex("00fFa1", &mut buf),
+        Some(&[0x00, 0xff, 0xa1][..])
+    );
+    assert_eq!(decode_hex("", &mut buf), Some(&[][..]));
+    assert_eq!(decode_hex("0011223344", &mut buf), None);
+    assert_eq!(decode_hex("0x11", &mut buf), None);
+    assert_eq!(decode_hex("001", &mut buf), None);
+}
@@ -0,0 +1,35 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use digest::Output;
+use digest::{FixedOutputReset, Mac};
+
+/// MAC which can be reset to its freshly keyed state.
+///
+/// This is a shorthand for the `Mac + FixedOutputReset` bound and is
+/// implemented for all types which satisfy it, e.g. [`Cmac`] or [`Hmac`]
+/// with enabled `reset` feature.
+///
+/// [`Cmac`]: https://docs.rs/cmac
+/// [`Hmac`]: https://docs.rs/hmac
+pub trait ResettableMac: Mac + FixedOutputReset {}
+
+impl<T: Mac + FixedOutputReset> ResettableMac for T {}
+
+/// Compute tag for each message in `messages` using `mac`.
+///
+/// The MAC instance is reset before the first message and after each of
+/// the processed messages, i.e. any data fed into `mac` before calling this
+/// function is discarded and on return `mac` is in its freshly keyed state.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn compute_many<M: ResettableMac>(mac: &mut M, messages: &[&[u8]]) -> Vec<Output<M>> {
+    Mac::reset(mac);
+    messages
+        .iter()
+        .map(|msg| {
+            Mac::update(mac, msg);
+            mac.finalize_reset().into_bytes()
+        })
+        .collect()
+}
@@ -0,0 +1,110 @@
+use core::ops::Add;
+use digest::{
+    array::ArraySize,
+    typenum::{Sum, Unsigned},
+    FixedOutput, FixedOutputReset, MacMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+/// MAC which combines two MACs for defense-in-depth.
+///
+/// Input data is passed to both inner MACs and the resulting tag is
+/// the concatenation of their tags, i.e. `A(data) || B(data)`. Forging it
+/// requires breaking both of the MACs, which can be useful in hybrid
+/// or transitional deployments.
+///
+/// Tag verification using the [`Mac`][digest::Mac] trait methods compares
+/// both halves of the tag in constant time.
+///
+/// # Examples
+///
+/// ```rust
+/// use aes::Aes128;
+/// use cmac::Cmac;
+/// use hmac::{Hmac, KeyInit, Mac};
+/// use mac_utils::Combined;
+/// use sha2::Sha256;
+///
+/// let a = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+/// let b = Cmac::<Aes128>::new_from_slice(b"very secret key.").unwrap();
+/// let mut mac = Combined::new(a, b);
+/// mac.update(b"input message");
+/// let tag = mac.finalize().into_bytes();
+/// assert_eq!(tag.len(), 32 + 16);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Combined<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> Combined<A, B> {
+    /// Combine two keyed MAC instances.
+    #[inline]
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+
+    /// Get the inner MACs.
+    #[inline]
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+
+impl<A, B> OutputSizeUser for Combined<A, B>
+where
+    A: OutputSizeUser,
+    B: OutputSizeUser,
+    A::OutputSize: Add<B::OutputSize>,
+    Sum<A::OutputSize, B::OutputSize>: ArraySize,
+{
+    type OutputSize = Sum<A::OutputSize, B::OutputSize>;
+}
+
+impl<A: MacMarker, B: MacMarker> MacMarker for Combined<A, B> {}
+
+impl<A: Update, B: Update> Update for Combined<A, B> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.a.update(data);
+        self.b.update(data);
+    }
+}
+
+impl<A, B> FixedOutput for Combined<A, B>
+where
+    A: FixedOutput,
+    B: FixedOutput,
+    A::OutputSize: Add<B::OutputSize>,
+    Sum<A::OutputSize, B::OutputSize>: ArraySize,
+{
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        let (a_out, b_out) = out.split_at_mut(A::OutputSize::USIZE);
+        a_out.copy_from_slice(&self.a.finalize_fixed());
+        b_out.copy_from_slice(&self.b.finalize_fixed());
+    }
+}
+
+impl<A: Reset, B: Reset> Reset for Combined<A, B> {
+    #[inline]
+    fn reset(&mut self) {
+        self.a.reset();
+        self.b.reset();
+    }
+}
+
+impl<A, B> FixedOutputReset for Combined<A, B>
+where
+    A: FixedOutputReset,
+    B: FixedOutputReset,
+    A::OutputSize: Add<B::OutputSize>,
+    Sum<A::OutputSize, B::OutputSize>: ArraySize,
+{
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        let (a_out, b_out) = out.split_at_mut(A::OutputSize::USIZE);
+        a_out.copy_from_slice(&self.a.finalize_fixed_reset());
+        b_out.copy_from_slice(&self.b.finalize_fixed_reset());
+    }
+}
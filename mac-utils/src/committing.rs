@@ -0,0 +1,139 @@
+use core::ops::Add;
+use digest::{
+    array::ArraySize,
+    typenum::{Sum, Unsigned},
+    FixedOutput, FixedOutputReset, Mac, MacMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+/// Domain separation constant used for computing key commitments.
+const COMMITMENT_LABEL: &[u8] = b"RustCrypto MAC key commitment";
+/// Prefix of the inner MAC input for messages.
+const MESSAGE_PREFIX: u8 = 0x00;
+/// Prefix of the inner MAC input for the key commitment.
+const COMMITMENT_PREFIX: u8 = 0x01;
+
+/// Key-committing MAC wrapper.
+///
+/// In addition to the normal tag, the wrapper outputs a commitment to the key,
+/// computed as the MAC of a fixed domain separation constant. The resulting
+/// tag is `MAC(0x00 || message) || MAC(0x01 || label)`. The prefixes separate
+/// messages from the commitment input, so the commitment can not be used as
+/// a tag of any message.
+///
+/// Tag verification using the [`Mac`] trait methods checks both parts of
+/// the tag in constant time.
+///
+/// # Security
+///
+/// The tag is bound to the key only if it's infeasible to find two keys for
+/// which `M` produces the same output, even when the keys are chosen by an
+/// attacker. This holds for hash-based MACs such as HMAC over a collision
+/// resistant hash function. With them finding a message and tag pair which
+/// is valid under two different keys requires the key commitments to collide
+/// as well. Such key commitment is needed for preventing multi-key attacks
+/// on AEAD constructions.
+///
+/// Block cipher based MACs (e.g. CMAC, PMAC or CBC-MAC) do **not** commit
+/// to the key. Their security is defined only for secret keys and their
+/// output is limited to the cipher block size, so the wrapper must not be
+/// relied on for key commitment when used with them.
+///
+/// # Examples
+///
+/// ```rust
+/// use hmac::{Hmac, KeyInit, Mac};
+/// use mac_utils::Committing;
+/// use sha2::Sha256;
+///
+/// let mac = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+/// let mut mac = Committing::new(mac);
+/// mac.update(b"input message");
+/// let tag = mac.finalize().into_bytes();
+/// assert_eq!(tag.len(), 64);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Committing<M: OutputSizeUser> {
+    inner: M,
+    commitment: Output<M>,
+}
+
+impl<M: Mac + FixedOutput + Clone> Committing<M> {
+    /// Wrap `inner` MAC and compute the key commitment.
+    ///
+    /// `inner` is expected to be in its freshly keyed state.
+    #[inline]
+    pub fn new(mut inner: M) -> Self {
+        let mut commitment = inner.clone();
+        Update::update(&mut commitment, &[COMMITMENT_PREFIX]);
+        Update::update(&mut commitment, COMMITMENT_LABEL);
+        let commitment = commitment.finalize_fixed();
+        Update::update(&mut inner, &[MESSAGE_PREFIX]);
+        Self { inner, commitment }
+    }
+}
+
+impl<M: OutputSizeUser> Committing<M> {
+    /// Get the key commitment.
+    ///
+    /// The commitment does not reveal the key, but it identifies it, so
+    /// it must not be used in settings where keys should be unlinkable.
+    #[inline]
+    pub fn commitment(&self) -> &Output<M> {
+        &self.commitment
+    }
+}
+
+impl<M> OutputSizeUser for Committing<M>
+where
+    M: OutputSizeUser,
+    M::OutputSize: Add<M::OutputSize>,
+    Sum<M::OutputSize, M::OutputSize>: ArraySize,
+{
+    type OutputSize = Sum<M::OutputSize, M::OutputSize>;
+}
+
+impl<M: OutputSizeUser + MacMarker> MacMarker for Committing<M> {}
+
+impl<M: OutputSizeUser + Update> Update for Committing<M> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+}
+
+impl<M> FixedOutput for Committing<M>
+where
+    M: FixedOutput,
+    M::OutputSize: Add<M::OutputSize>,
+    Sum<M::OutputSize, M::OutputSize>: ArraySize,
+{
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        let (tag, commitment) = out.split_at_mut(M::OutputSize::USIZE);
+        tag.copy_from_slice(&self.inner.finalize_fixed());
+        commitment.copy_from_slice(&self.commitment);
+    }
+}
+
+impl<M: OutputSizeUser + Update + Reset> Reset for Committing<M> {
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.inner.update(&[MESSAGE_PREFIX]);
+    }
+}
+
+impl<M> FixedOutputReset for Committing<M>
+where
+    M: FixedOutputReset,
+    M::OutputSize: Add<M::OutputSize>,
+    Sum<M::OutputSize, M::OutputSize>: ArraySize,
+{
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        let (tag, commitment) = out.split_at_mut(M::OutputSize::USIZE);
+        tag.copy_from_slice(&self.inner.finalize_fixed_reset());
+        commitment.copy_from_slice(&self.commitment);
+        self.inner.update(&[MESSAGE_PREFIX]);
+    }
+}
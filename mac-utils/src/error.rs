@@ -0,0 +1,29 @@
+use core::fmt;
+
+/// Error returned by [`Redundant`][crate::Redundant] when the independently
+/// computed tags differ, e.g. because of a transient hardware fault.
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct FaultDetected;
+
+impl fmt::Display for FaultDetected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MAC fault detected: redundant tags differ")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FaultDetected {}
+
+/// Error returned by [`Limited::try_update`][crate::Limited::try_update]
+/// when the input limit is exceeded.
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct LimitExceeded;
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MAC input length limit exceeded")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LimitExceeded {}
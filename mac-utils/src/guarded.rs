@@ -0,0 +1,69 @@
+use digest::{Mac, MacError};
+
+/// Tag verifier which tracks consecutive verification failures.
+///
+/// Repeated verification failures may indicate a forgery attempt. The verifier
+/// counts consecutive failures and, once their number reaches the configured
+/// threshold, invokes the user-supplied callback on each subsequent failure
+/// with the current failure count. It can be used by applications to implement
+/// lockout or to add delays. The counter is reset on successful verification.
+///
+/// Tag verification itself is performed in constant time by [`Mac::verify_slice`].
+///
+/// # Examples
+///
+/// ```rust
+/// use hmac::{Hmac, KeyInit, Mac};
+/// use mac_utils::GuardedVerifier;
+/// use sha2::Sha256;
+///
+/// let mac = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+/// let mut locked = false;
+/// let mut verifier = GuardedVerifier::new(3, |_failures| locked = true);
+///
+/// for _ in 0..3 {
+///     let res = verifier.verify(mac.clone().chain_update(b"message"), &[0u8; 32]);
+///     assert!(res.is_err());
+/// }
+/// drop(verifier);
+/// assert!(locked);
+/// ```
+#[derive(Clone, Debug)]
+pub struct GuardedVerifier<F> {
+    failures: u64,
+    threshold: u64,
+    on_threshold: F,
+}
+
+impl<F: FnMut(u64)> GuardedVerifier<F> {
+    /// Create new verifier which invokes `on_threshold` after `threshold`
+    /// consecutive failures.
+    #[inline]
+    pub fn new(threshold: u64, on_threshold: F) -> Self {
+        Self {
+            failures: 0,
+            threshold,
+            on_threshold,
+        }
+    }
+
+    /// Number of consecutive verification failures.
+    #[inline]
+    pub fn failures(&self) -> u64 {
+        self.failures
+    }
+
+    /// Check tag of the data processed by `mac`.
+    pub fn verify<M: Mac>(&mut self, mac: M, tag: &[u8]) -> Result<(), MacError> {
+        let res = mac.verify_slice(tag);
+        if res.is_ok() {
+            self.failures = 0;
+        } else {
+            self.failures = self.failures.saturating_add(1);
+            if self.failures >= self.threshold {
+                (self.on_threshold)(self.failures);
+            }
+        }
+        res
+    }
+}
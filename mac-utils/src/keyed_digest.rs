@@ -0,0 +1,109 @@
+use core::fmt;
+use digest::{
+    FixedOutput, FixedOutputReset, InvalidLength, KeyInit, Output, OutputSizeUser, Reset, Update,
+};
+
+/// Adapter which allows using a keyed MAC in place of an unkeyed hash.
+///
+/// The adapter keeps a copy of the freshly keyed MAC state, so it can be
+/// reset to it without access to the key and without support of
+/// [`Reset`] by the wrapped MAC. It implements the traits used by
+/// hash-generic code: [`Update`], [`FixedOutput`], [`FixedOutputReset`]
+/// and [`Reset`], and thus with enabled `alloc` feature also
+/// [`DynDigest`][digest::DynDigest].
+///
+/// Note that [`Digest`][digest::Digest] can not be implemented, since it
+/// requires construction of instances using [`Default`], i.e. without a key.
+///
+/// The output is the raw MAC tag, so comparisons of it should be performed
+/// in constant time (e.g. using [`Mac::verify`][digest::Mac::verify]).
+///
+/// # Examples
+///
+/// ```rust
+/// use hmac::Hmac;
+/// use mac_utils::{
+///     digest::{FixedOutputReset, Update},
+///     KeyedAsDigest,
+/// };
+/// use sha2::Sha256;
+///
+/// // Hash-generic function
+/// fn hash_parts<D: Update + FixedOutputReset>(d: &mut D, parts: &[&[u8]]) -> Vec<u8> {
+///     parts
+///         .iter()
+///         .flat_map(|p| {
+///             d.update(p);
+///             d.finalize_fixed_reset()
+///         })
+///         .collect()
+/// }
+///
+/// let mut d = KeyedAsDigest::<Hmac<Sha256>>::new_from_slice(b"my secret and secure key")?;
+/// let out = hash_parts(&mut d, &[b"first", b"second"]);
+/// assert_eq!(out.len(), 64);
+/// # Ok::<(), mac_utils::digest::InvalidLength>(())
+/// ```
+#[derive(Clone)]
+pub struct KeyedAsDigest<M> {
+    initial: M,
+    current: M,
+}
+
+impl<M: Clone> KeyedAsDigest<M> {
+    /// Wrap a freshly keyed MAC instance.
+    #[inline]
+    pub fn new(mac: M) -> Self {
+        Self {
+            initial: mac.clone(),
+            current: mac,
+        }
+    }
+}
+
+impl<M: KeyInit + Clone> KeyedAsDigest<M> {
+    /// Create new adapter keyed with `key`.
+    #[inline]
+    pub fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+        M::new_from_slice(key).map(Self::new)
+    }
+}
+
+impl<M: OutputSizeUser> OutputSizeUser for KeyedAsDigest<M> {
+    type OutputSize = M::OutputSize;
+}
+
+impl<M: Update> Update for KeyedAsDigest<M> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.current.update(data);
+    }
+}
+
+impl<M: FixedOutput> FixedOutput for KeyedAsDigest<M> {
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        self.current.finalize_into(out);
+    }
+}
+
+impl<M: Clone> Reset for KeyedAsDigest<M> {
+    #[inline]
+    fn reset(&mut self) {
+        self.current = self.initial.clone();
+    }
+}
+
+impl<M: FixedOutput + Clone> FixedOutputReset for KeyedAsDigest<M> {
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        let mac = core::mem::replace(&mut self.current, self.initial.clone());
+        mac.finalize_into(out);
+    }
+}
+
+impl<M> fmt::Debug for KeyedAsDigest<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("KeyedAsDigest { ... }")
+    }
+}
@@ -0,0 +1,88 @@
+//! Generic helpers and combinators for Message Authentication Codes (MACs).
+//!
+//! Everything in this crate is written against the [`Mac`] trait from the
+//! [`digest`] crate, so it works with any MAC implementation from the
+//! [`RustCrypto/MACs`] repository (e.g. [`hmac`] or [`cmac`]).
+//!
+//! # Examples
+//! Computing tags for a batch of messages with a single resettable MAC:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use hmac::{Hmac, KeyInit, Mac};
+//! use mac_utils::compute_many;
+//! use sha2::Sha256;
+//!
+//! let mut mac = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+//! let tags = compute_many(&mut mac, &[b"first message", b"second message"]);
+//! assert_eq!(tags.len(), 2);
+//! # }
+//! ```
+//!
+//! # Crate features
+//! - `alloc`: enables functionality which requires heap allocation
+//!   (e.g. [`compute_many`])
+//! - `std`: enables functionality dependent on `std` (implies `alloc`)
+//! - `rand_core`: enables key generation using `rand_core` RNGs
+//!   (re-exported as [`digest::rand_core`])
+//! - `dev`: enables the [`dev`] module with helpers for defining tests
+//!   over hex-encoded test vectors (implies `alloc`)
+//! - `zeroize`: implements [`ZeroizeOnDrop`][digest::zeroize::ZeroizeOnDrop]
+//!   for [`TruncatedCore`] over cores which implement it
+//!
+//! [`RustCrypto/MACs`]: https://github.com/RustCrypto/MACs
+//! [`hmac`]: https://docs.rs/hmac
+//! [`cmac`]: https://docs.rs/cmac
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/media/26acc39f/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/media/26acc39f/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![warn(missing_docs, rust_2018_idioms)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use digest::{self, KeyInit, Mac};
+
+mod batch;
+mod combined;
+mod committing;
+mod error;
+mod guarded;
+mod keyed_digest;
+mod limited;
+mod nested;
+mod one_time;
+mod prefixed;
+mod prf;
+mod redundant;
+mod seq;
+mod stream;
+
+#[cfg(feature = "alloc")]
+pub use batch::compute_many;
+pub use batch::ResettableMac;
+pub use combined::Combined;
+pub use committing::Committing;
+pub use error::{FaultDetected, LimitExceeded};
+pub use guarded::GuardedVerifier;
+pub use keyed_digest::KeyedAsDigest;
+pub use limited::Limited;
+#[cfg(feature = "dev")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+pub use mac_common::{dev, new_mac_hex_test, new_resettable_mac_hex_test};
+pub use mac_common::{LabeledMacError, MacExt};
+pub use mac_common::{Truncated, TruncatedCore};
+pub use nested::Nested;
+pub use one_time::OneTime;
+pub use prefixed::Prefixed;
+pub use prf::{Prf, PrfAdapter};
+pub use redundant::Redundant;
+pub use seq::SeqMac;
+pub use stream::StreamVerifier;
@@ -0,0 +1,119 @@
+use crate::LimitExceeded;
+use digest::{CtOutput, FixedOutputReset, Mac, MacError, Output};
+
+/// MAC wrapper which limits the total length of processed input.
+///
+/// It can be used to bound amount of work spent on messages received from
+/// untrusted peers. Data which does not fit into the limit is rejected
+/// by [`Limited::try_update`] without being passed to the inner MAC.
+///
+/// The wrapper intentionally does not implement the [`Mac`] trait, since
+/// its infallible `update` method can not report an exceeded limit.
+///
+/// # Examples
+///
+/// ```rust
+/// use hmac::{Hmac, KeyInit};
+/// use mac_utils::Limited;
+/// use sha2::Sha256;
+///
+/// let mac = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+/// let mut mac = Limited::new(mac, 16);
+///
+/// assert!(mac.try_update(b"0123456789").is_ok());
+/// assert!(mac.try_update(b"0123456789").is_err());
+/// assert_eq!(mac.remaining(), 6);
+///
+/// let tag = mac.finalize();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Limited<M> {
+    inner: M,
+    processed: u64,
+    max_len: u64,
+}
+
+impl<M> Limited<M> {
+    /// Wrap `inner` MAC and limit its input to `max_len` bytes.
+    #[inline]
+    pub fn new(inner: M, max_len: u64) -> Self {
+        Self {
+            inner,
+            processed: 0,
+            max_len,
+        }
+    }
+
+    /// Number of bytes processed since construction or the last reset.
+    #[inline]
+    pub fn processed(&self) -> u64 {
+        self.processed
+    }
+
+    /// Number of bytes which can be processed before reaching the limit.
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.max_len - self.processed
+    }
+
+    /// Get the wrapped MAC.
+    #[inline]
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+}
+
+impl<M: Mac> Limited<M> {
+    /// Update state using the provided data.
+    ///
+    /// Returns [`LimitExceeded`] without processing `data` if its length
+    /// exceeds the remaining part of the limit.
+    #[inline]
+    pub fn try_update(&mut self, data: &[u8]) -> Result<(), LimitExceeded> {
+        let len = u64::try_from(data.len()).map_err(|_| LimitExceeded)?;
+        if len > self.remaining() {
+            return Err(LimitExceeded);
+        }
+        self.processed += len;
+        Mac::update(&mut self.inner, data);
+        Ok(())
+    }
+
+    /// Obtain the result of a MAC computation over the accepted data.
+    #[inline]
+    pub fn finalize(self) -> CtOutput<M> {
+        self.inner.finalize()
+    }
+
+    /// Check if tag/code value is correct for the accepted data.
+    #[inline]
+    pub fn verify(self, tag: &Output<M>) -> Result<(), MacError> {
+        self.inner.verify(tag)
+    }
+
+    /// Check tag correctness for the accepted data.
+    ///
+    /// Returns [`MacError`] if `tag` is not valid or not equal in length
+    /// to MAC's output.
+    #[inline]
+    pub fn verify_slice(self, tag: &[u8]) -> Result<(), MacError> {
+        self.inner.verify_slice(tag)
+    }
+}
+
+impl<M: Mac + FixedOutputReset> Limited<M> {
+    /// Obtain the result of a MAC computation over the accepted data
+    /// and reset the instance.
+    #[inline]
+    pub fn finalize_reset(&mut self) -> CtOutput<M> {
+        self.processed = 0;
+        Mac::finalize_reset(&mut self.inner)
+    }
+
+    /// Reset MAC instance to its initial state.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.processed = 0;
+        Mac::reset(&mut self.inner);
+    }
+}
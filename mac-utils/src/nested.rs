@@ -0,0 +1,87 @@
+use digest::{FixedOutput, FixedOutputReset, MacMarker, Output, OutputSizeUser, Reset, Update};
+
+/// Nested MAC which computes the outer MAC over the tag of the inner MAC.
+///
+/// Input data is passed to the inner MAC and the resulting tag is
+/// `O(I(data))`. Both MACs are keyed independently by the caller.
+///
+/// Tag verification using the [`Mac`][digest::Mac] trait methods is
+/// performed in constant time.
+///
+/// # Examples
+///
+/// ```rust
+/// use aes::Aes128;
+/// use cmac::Cmac;
+/// use hmac::{Hmac, KeyInit, Mac};
+/// use mac_utils::Nested;
+/// use sha2::Sha256;
+///
+/// // HMAC-SHA256 of CMAC-AES128 tag
+/// let inner = Cmac::<Aes128>::new_from_slice(b"very secret key.").unwrap();
+/// let outer = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+/// let mut mac = Nested::new(inner, outer);
+/// mac.update(b"input message");
+/// let tag = mac.finalize().into_bytes();
+/// assert_eq!(tag.len(), 32);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Nested<I, O> {
+    inner: I,
+    outer: O,
+}
+
+impl<I, O> Nested<I, O> {
+    /// Combine keyed inner and outer MAC instances.
+    ///
+    /// `outer` is expected to be in its freshly keyed state.
+    #[inline]
+    pub fn new(inner: I, outer: O) -> Self {
+        Self { inner, outer }
+    }
+
+    /// Get the inner and outer MACs.
+    #[inline]
+    pub fn into_inner(self) -> (I, O) {
+        (self.inner, self.outer)
+    }
+}
+
+impl<I, O: OutputSizeUser> OutputSizeUser for Nested<I, O> {
+    type OutputSize = O::OutputSize;
+}
+
+impl<I: MacMarker, O: MacMarker> MacMarker for Nested<I, O> {}
+
+impl<I: Update, O> Update for Nested<I, O> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+}
+
+impl<I: FixedOutput, O: FixedOutput> FixedOutput for Nested<I, O> {
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        let Self { inner, mut outer } = self;
+        outer.update(&inner.finalize_fixed());
+        outer.finalize_into(out);
+    }
+}
+
+impl<I: Reset, O: Reset> Reset for Nested<I, O> {
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.outer.reset();
+    }
+}
+
+impl<I: FixedOutputReset, O: FixedOutputReset> FixedOutputReset for Nested<I, O> {
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        let tag = self.inner.finalize_fixed_reset();
+        self.outer.update(&tag);
+        self.outer.finalize_into_reset(out);
+    }
+}
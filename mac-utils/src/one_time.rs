@@ -0,0 +1,143 @@
+use core::fmt;
+use digest::{
+    crypto_common::KeySizeUser, FixedOutput, InvalidLength, Key, KeyInit, MacMarker, Output,
+    OutputSizeUser, Update,
+};
+
+/// MAC wrapper enforcing single-use semantics of one-time authenticators
+/// (e.g. Poly1305).
+///
+/// A one-time key must never be used for computing tags of more than one
+/// message. The wrapper does not implement `Clone`, `Reset` and
+/// `FixedOutputReset`, so with the [`Mac`][digest::Mac] trait methods,
+/// which consume the instance, it's impossible to reuse it after
+/// finalization:
+///
+/// ```compile_fail
+/// use hmac::{Hmac, KeyInit, Mac};
+/// use mac_utils::OneTime;
+/// use sha2::Sha256;
+///
+/// let mut mac = OneTime::<Hmac<Sha256>>::new_from_slice(b"one-time key").unwrap();
+/// mac.update(b"first message");
+/// let tag = mac.finalize_reset();
+/// ```
+///
+/// ```compile_fail
+/// use hmac::{Hmac, KeyInit, Mac};
+/// use mac_utils::OneTime;
+/// use sha2::Sha256;
+///
+/// let mac = OneTime::<Hmac<Sha256>>::new_from_slice(b"one-time key").unwrap();
+/// let tag1 = mac.clone().chain_update(b"first message").finalize();
+/// let tag2 = mac.chain_update(b"second message").finalize();
+/// ```
+///
+/// For code which can not move the instance, [`OneTime::finalize_once`]
+/// finalizes it by reference and tracks the used state at runtime.
+///
+/// Note that single-use is enforced only for the wrapper itself, so it
+/// should be created using the [`KeyInit`] trait, or from an instance
+/// which is not cloned or reset by the caller.
+///
+/// # Examples
+///
+/// ```rust
+/// use hmac::{Hmac, KeyInit, Mac};
+/// use mac_utils::OneTime;
+/// use sha2::Sha256;
+///
+/// let mut mac = OneTime::<Hmac<Sha256>>::new_from_slice(b"one-time key").unwrap();
+/// mac.update(b"input message");
+/// let tag = mac.finalize_once();
+/// assert!(mac.is_used());
+/// ```
+pub struct OneTime<M> {
+    inner: Option<M>,
+}
+
+impl<M> OneTime<M> {
+    /// Wrap `inner` MAC instance.
+    #[inline]
+    pub fn new(inner: M) -> Self {
+        Self { inner: Some(inner) }
+    }
+
+    /// Check whether the instance was finalized by [`OneTime::finalize_once`].
+    #[inline]
+    pub fn is_used(&self) -> bool {
+        self.inner.is_none()
+    }
+
+    #[inline]
+    fn inner_mut(&mut self) -> &mut M {
+        self.inner.as_mut().expect("one-time MAC instance reused")
+    }
+}
+
+impl<M: FixedOutput> OneTime<M> {
+    /// Finalize the instance by reference and mark it as used.
+    ///
+    /// The returned tag is a raw byte array, so it should not be compared
+    /// using `==`.
+    ///
+    /// # Panics
+    /// If the instance was already finalized.
+    #[inline]
+    pub fn finalize_once(&mut self) -> Output<M> {
+        self.inner
+            .take()
+            .expect("one-time MAC instance reused")
+            .finalize_fixed()
+    }
+}
+
+impl<M: KeySizeUser> KeySizeUser for OneTime<M> {
+    type KeySize = M::KeySize;
+}
+
+impl<M: KeyInit> KeyInit for OneTime<M> {
+    #[inline]
+    fn new(key: &Key<Self>) -> Self {
+        Self::new(M::new(key))
+    }
+
+    #[inline]
+    fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+        M::new_from_slice(key).map(Self::new)
+    }
+}
+
+impl<M: OutputSizeUser> OutputSizeUser for OneTime<M> {
+    type OutputSize = M::OutputSize;
+}
+
+impl<M: MacMarker> MacMarker for OneTime<M> {}
+
+impl<M: Update> Update for OneTime<M> {
+    /// # Panics
+    /// If the instance was already finalized.
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.inner_mut().update(data);
+    }
+}
+
+impl<M: FixedOutput> FixedOutput for OneTime<M> {
+    /// # Panics
+    /// If the instance was already finalized.
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        self.inner
+            .expect("one-time MAC instance reused")
+            .finalize_into(out);
+    }
+}
+
+impl<M> fmt::Debug for OneTime<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OneTime")
+            .field("used", &self.is_used())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,89 @@
+use digest::{FixedOutput, FixedOutputReset, MacMarker, Output, OutputSizeUser, Reset, Update};
+
+/// MAC wrapper which prepends a fixed header to every message.
+///
+/// The header is fed into the inner MAC on construction and after each
+/// reset, so it can not be forgotten by accident. Binding messages to
+/// a protocol-specific context header helps to prevent cross-protocol attacks.
+///
+/// # Examples
+///
+/// ```rust
+/// use hmac::{Hmac, KeyInit, Mac};
+/// use mac_utils::Prefixed;
+/// use sha2::Sha256;
+///
+/// let key = b"my secret and secure key";
+/// let mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+/// let mut mac = Prefixed::new(mac, b"my protocol v1");
+///
+/// mac.update(b"input message");
+/// let tag = mac.finalize_reset();
+///
+/// let expected = Hmac::<Sha256>::new_from_slice(key)
+///     .unwrap()
+///     .chain_update(b"my protocol v1")
+///     .chain_update(b"input message")
+///     .finalize();
+/// assert_eq!(tag.into_bytes(), expected.into_bytes());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Prefixed<M, H> {
+    inner: M,
+    header: H,
+}
+
+impl<M: Update, H: AsRef<[u8]>> Prefixed<M, H> {
+    /// Wrap `inner` MAC and feed `header` into it.
+    ///
+    /// `inner` is expected to be in its freshly keyed state.
+    #[inline]
+    pub fn new(mut inner: M, header: H) -> Self {
+        inner.update(header.as_ref());
+        Self { inner, header }
+    }
+}
+
+impl<M, H> Prefixed<M, H> {
+    /// Get the header prepended to messages.
+    #[inline]
+    pub fn header(&self) -> &H {
+        &self.header
+    }
+}
+
+impl<M: OutputSizeUser, H> OutputSizeUser for Prefixed<M, H> {
+    type OutputSize = M::OutputSize;
+}
+
+impl<M: MacMarker, H> MacMarker for Prefixed<M, H> {}
+
+impl<M: Update, H> Update for Prefixed<M, H> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+}
+
+impl<M: FixedOutput, H> FixedOutput for Prefixed<M, H> {
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        self.inner.finalize_into(out);
+    }
+}
+
+impl<M: Update + Reset, H: AsRef<[u8]>> Reset for Prefixed<M, H> {
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.inner.update(self.header.as_ref());
+    }
+}
+
+impl<M: FixedOutputReset, H: AsRef<[u8]>> FixedOutputReset for Prefixed<M, H> {
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        self.inner.finalize_into_reset(out);
+        self.inner.update(self.header.as_ref());
+    }
+}
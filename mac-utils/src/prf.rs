@@ -0,0 +1,59 @@
+use core::{fmt, marker::PhantomData};
+use digest::{crypto_common::KeySizeUser, InvalidLength, KeyInit, Mac, Output, OutputSizeUser};
+
+/// Pseudorandom function keyed with a byte slice.
+///
+/// The trait provides a uniform interface for protocol frameworks which
+/// abstract over the used MAC or PRF. The nominal key size is provided by
+/// the [`KeySizeUser`] supertrait, but implementations may accept keys of
+/// other lengths (e.g. HMAC accepts keys of any length).
+pub trait Prf: OutputSizeUser + KeySizeUser {
+    /// Compute PRF output for `data` under `key`.
+    ///
+    /// Returns [`InvalidLength`] if length of `key` is not supported.
+    fn prf(key: &[u8], data: &[u8]) -> Result<Output<Self>, InvalidLength>;
+}
+
+/// Adapter which implements [`Prf`] for any MAC type `M`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aes::Aes128;
+/// use cmac::Cmac;
+/// use hmac::Hmac;
+/// use mac_utils::{Prf, PrfAdapter};
+/// use sha2::Sha256;
+///
+/// fn derive<P: Prf>(key: &[u8]) -> usize {
+///     P::prf(key, b"context").unwrap().len()
+/// }
+///
+/// assert_eq!(derive::<PrfAdapter<Hmac<Sha256>>>(b"my secret and secure key"), 32);
+/// assert_eq!(derive::<PrfAdapter<Cmac<Aes128>>>(b"very secret key."), 16);
+/// ```
+pub struct PrfAdapter<M> {
+    _pd: PhantomData<M>,
+}
+
+impl<M: OutputSizeUser> OutputSizeUser for PrfAdapter<M> {
+    type OutputSize = M::OutputSize;
+}
+
+impl<M: KeySizeUser> KeySizeUser for PrfAdapter<M> {
+    type KeySize = M::KeySize;
+}
+
+impl<M: Mac + KeyInit> Prf for PrfAdapter<M> {
+    #[inline]
+    fn prf(key: &[u8], data: &[u8]) -> Result<Output<Self>, InvalidLength> {
+        let mac = M::new_from_slice(key)?.chain_update(data);
+        Ok(mac.finalize().into_bytes())
+    }
+}
+
+impl<M> fmt::Debug for PrfAdapter<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PrfAdapter")
+    }
+}
@@ -0,0 +1,83 @@
+use crate::FaultDetected;
+use digest::{CtOutput, Mac, MacError, Output};
+use subtle::ConstantTimeEq;
+
+/// MAC wrapper which computes every tag twice for fault detection.
+///
+/// The wrapper maintains two independent instances of `M` which are fed
+/// the same data. On finalization both tags are computed and compared in
+/// constant time, and the tag is returned only if they agree. It protects
+/// against transient faults (e.g. glitches or bit flips) corrupting one
+/// of the computations in safety-critical deployments.
+///
+/// Note that the wrapper doubles the cost of MAC computation.
+///
+/// # Examples
+///
+/// ```rust
+/// use hmac::{Hmac, KeyInit};
+/// use mac_utils::Redundant;
+/// use sha2::Sha256;
+///
+/// let mac = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+/// let mut mac = Redundant::new(mac);
+/// mac.update(b"input message");
+/// let tag = mac.finalize().expect("fault detected");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Redundant<M> {
+    first: M,
+    second: M,
+}
+
+impl<M: Mac + Clone> Redundant<M> {
+    /// Wrap `inner` MAC, the second instance is created by cloning it.
+    #[inline]
+    pub fn new(inner: M) -> Self {
+        Self::from_pair(inner.clone(), inner)
+    }
+}
+
+impl<M: Mac> Redundant<M> {
+    /// Wrap two separately initialized instances of MAC.
+    ///
+    /// Both instances must be initialized with the same key.
+    #[inline]
+    pub fn from_pair(first: M, second: M) -> Self {
+        Self { first, second }
+    }
+
+    /// Update state of both instances using the provided data.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.first.update(data);
+        self.second.update(data);
+    }
+
+    /// Obtain the tag if both instances have computed the same value.
+    ///
+    /// Returns [`FaultDetected`] if the computed tags differ.
+    pub fn finalize(self) -> Result<CtOutput<M>, FaultDetected> {
+        let first = self.first.finalize();
+        let second = self.second.finalize();
+        // `CtOutput` equality is constant time
+        if first == second {
+            Ok(first)
+        } else {
+            Err(FaultDetected)
+        }
+    }
+
+    /// Check that both instances agree and the tag is equal to `tag`.
+    ///
+    /// A detected fault is reported as a verification failure, i.e. the
+    /// check fails closed.
+    pub fn verify(self, tag: &Output<M>) -> Result<(), MacError> {
+        let calc = self.finalize().map_err(|_| MacError)?.into_bytes();
+        if calc.ct_eq(tag).into() {
+            Ok(())
+        } else {
+            Err(MacError)
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::ResettableMac;
+use digest::{CtOutput, Mac, MacError};
+use subtle::ConstantTimeEq;
+
+/// MAC wrapper which binds messages to their sequence numbers.
+///
+/// The wrapper maintains a `u64` counter and prepends its big-endian
+/// encoding to each message. The counter is incremented after each
+/// computed tag and after each successful verification, so replayed,
+/// dropped, or reordered messages fail verification on the receiving side.
+///
+/// # Examples
+///
+/// ```rust
+/// use hmac::{Hmac, KeyInit};
+/// use mac_utils::SeqMac;
+/// use sha2::Sha256;
+///
+/// let key = b"my secret and secure key";
+/// let mut sender = SeqMac::new(Hmac::<Sha256>::new_from_slice(key).unwrap());
+/// let mut receiver = SeqMac::new(Hmac::<Sha256>::new_from_slice(key).unwrap());
+///
+/// sender.update(b"first message");
+/// let tag1 = sender.finalize().into_bytes();
+/// sender.update(b"second message");
+/// let tag2 = sender.finalize().into_bytes();
+/// assert_eq!(sender.counter(), 2);
+///
+/// // out of order message is rejected
+/// receiver.update(b"second message");
+/// assert!(receiver.verify(&tag2).is_err());
+///
+/// receiver.update(b"first message");
+/// receiver.verify(&tag1).unwrap();
+/// receiver.update(b"second message");
+/// receiver.verify(&tag2).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct SeqMac<M> {
+    inner: M,
+    counter: u64,
+}
+
+impl<M: ResettableMac> SeqMac<M> {
+    /// Wrap `inner` MAC and start counting from zero.
+    #[inline]
+    pub fn new(inner: M) -> Self {
+        Self::with_counter(inner, 0)
+    }
+
+    /// Wrap `inner` MAC and start counting from `counter`.
+    ///
+    /// Any data fed into `inner` before calling this method is discarded.
+    #[inline]
+    pub fn with_counter(mut inner: M, counter: u64) -> Self {
+        Mac::reset(&mut inner);
+        Mac::update(&mut inner, &counter.to_be_bytes());
+        Self { inner, counter }
+    }
+
+    /// Sequence number of the current message.
+    #[inline]
+    pub fn counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// Update state using the provided message data.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        Mac::update(&mut self.inner, data);
+    }
+
+    /// Obtain tag of the current message and move to the next one.
+    ///
+    /// # Panics
+    /// If the counter overflows.
+    pub fn finalize(&mut self) -> CtOutput<M> {
+        let tag = self.inner.finalize_reset();
+        self.next_message();
+        tag
+    }
+
+    /// Check tag of the current message in constant time.
+    ///
+    /// The counter is incremented only if the tag is correct. Otherwise
+    /// data of the current message is discarded and the counter stays
+    /// the same, so the message can be received again.
+    ///
+    /// # Panics
+    /// If the counter overflows.
+    pub fn verify(&mut self, tag: &[u8]) -> Result<(), MacError> {
+        let calc = self.inner.finalize_reset().into_bytes();
+        if calc[..].ct_eq(tag).into() {
+            self.next_message();
+            Ok(())
+        } else {
+            Mac::update(&mut self.inner, &self.counter.to_be_bytes());
+            Err(MacError)
+        }
+    }
+
+    fn next_message(&mut self) {
+        self.counter = self
+            .counter
+            .checked_add(1)
+            .expect("MAC sequence counter overflow");
+        Mac::update(&mut self.inner, &self.counter.to_be_bytes());
+    }
+}
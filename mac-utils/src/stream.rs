@@ -0,0 +1,100 @@
+use crate::ResettableMac;
+use core::cmp::min;
+use digest::{Mac, MacError, Output};
+use subtle::ConstantTimeEq;
+
+/// Verifier for messages and tags received in chunks.
+///
+/// Message data is passed to [`StreamVerifier::update`] and, once
+/// the message is complete, the expected tag is passed (possibly in several
+/// chunks) to [`StreamVerifier::update_tag`]. The verdict is produced by
+/// [`StreamVerifier::finish`], which compares the whole tag in constant time
+/// and resets the verifier, so it can be used for the next message.
+///
+/// # Examples
+///
+/// ```rust
+/// use hmac::{Hmac, KeyInit, Mac};
+/// use mac_utils::StreamVerifier;
+/// use sha2::Sha256;
+///
+/// let mac = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+/// let tag = mac.clone().chain_update(b"input message").finalize().into_bytes();
+///
+/// let mut verifier = StreamVerifier::new(mac);
+/// verifier.update(b"input ");
+/// verifier.update(b"message");
+/// verifier.update_tag(&tag[..10]);
+/// verifier.update_tag(&tag[10..]);
+/// verifier.finish().unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct StreamVerifier<M: ResettableMac> {
+    inner: M,
+    tag: Output<M>,
+    tag_len: usize,
+    invalid: bool,
+}
+
+impl<M: ResettableMac> StreamVerifier<M> {
+    /// Create new verifier from keyed MAC instance.
+    ///
+    /// Any data fed into `inner` before calling this method is discarded.
+    #[inline]
+    pub fn new(mut inner: M) -> Self {
+        Mac::reset(&mut inner);
+        Self {
+            inner,
+            tag: Default::default(),
+            tag_len: 0,
+            invalid: false,
+        }
+    }
+
+    /// Process the next chunk of message data.
+    ///
+    /// Message data passed after the first tag chunk results in
+    /// verification failure.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        if self.tag_len != 0 {
+            self.invalid = true;
+        }
+        Mac::update(&mut self.inner, data);
+    }
+
+    /// Process the next chunk of the expected tag.
+    ///
+    /// Tag bytes exceeding the MAC output size result in verification failure.
+    #[inline]
+    pub fn update_tag(&mut self, chunk: &[u8]) {
+        let n = min(chunk.len(), self.tag.len() - self.tag_len);
+        self.tag[self.tag_len..][..n].copy_from_slice(&chunk[..n]);
+        self.tag_len += n;
+        if n != chunk.len() {
+            self.invalid = true;
+        }
+    }
+
+    /// Check the received tag and reset the verifier.
+    ///
+    /// Returns [`MacError`] if the tag is not correct, incomplete, or
+    /// too long, or if message data was received after the tag.
+    pub fn finish(&mut self) -> Result<(), MacError> {
+        let calc = self.inner.finalize_reset().into_bytes();
+        // Length of tags is public, so it's fine to check it
+        // and the data order without constant time operations
+        let valid = !self.invalid && self.tag_len == calc.len();
+        let eq: bool = calc[..].ct_eq(&self.tag[..]).into();
+
+        self.tag = Default::default();
+        self.tag_len = 0;
+        self.invalid = false;
+
+        if valid & eq {
+            Ok(())
+        } else {
+            Err(MacError)
+        }
+    }
+}
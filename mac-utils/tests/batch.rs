@@ -0,0 +1,40 @@
+#![cfg(feature = "alloc")]
+
+use aes::Aes128;
+use cmac::Cmac;
+use hmac::Hmac;
+use mac_utils::{compute_many, KeyInit, Mac, ResettableMac};
+use sha2::Sha256;
+
+const MESSAGES: &[&[u8]] = &[b"", b"abc", &[0x42; 64], &[0x13; 100]];
+
+fn check<M: ResettableMac + KeyInit>(key: &[u8]) {
+    let mut mac = M::new_from_slice(key).unwrap();
+    // data fed before the call must be discarded
+    Mac::update(&mut mac, b"garbage");
+    let tags = compute_many(&mut mac, MESSAGES);
+    assert_eq!(tags.len(), MESSAGES.len());
+    for (msg, tag) in MESSAGES.iter().zip(tags.iter()) {
+        let expected = M::new_from_slice(key).unwrap().chain_update(msg);
+        expected.verify(tag).unwrap();
+    }
+    // instance must be left in the freshly keyed state
+    let expected = M::new_from_slice(key).unwrap().chain_update(b"abc");
+    assert_eq!(mac.chain_update(b"abc").finalize(), expected.finalize());
+}
+
+#[test]
+fn compute_many_hmac() {
+    check::<Hmac<Sha256>>(b"my secret and secure key");
+}
+
+#[test]
+fn compute_many_cmac() {
+    check::<Cmac<Aes128>>(b"very secret key.");
+}
+
+#[test]
+fn compute_many_empty() {
+    let mut mac = Cmac::<Aes128>::new_from_slice(b"very secret key.").unwrap();
+    assert!(compute_many(&mut mac, &[]).is_empty());
+}
@@ -0,0 +1,69 @@
+//! Check that cloned MAC instances do not share mutable state.
+use aes::Aes128;
+use belt_mac::BeltMac;
+use cbc_mac::CbcMac;
+use cmac::Cmac;
+use des::Des;
+use hmac::{Hmac, SimpleHmac};
+use mac_utils::{KeyInit, Mac};
+use pmac::Pmac;
+use retail_mac::RetailMac;
+use sha2::Sha256;
+
+fn check_clone<M: Mac + KeyInit + Clone>(key: &[u8]) {
+    let common = [0x11; 70];
+    let data1 = [0x22; 50];
+    let data2 = [0x33; 90];
+
+    let expected = |data: &[u8]| {
+        M::new_from_slice(key)
+            .unwrap()
+            .chain_update(common)
+            .chain_update(data)
+            .finalize()
+    };
+
+    let mut mac1 = M::new_from_slice(key).unwrap();
+    mac1.update(&common);
+    let mut mac2 = mac1.clone();
+
+    mac1.update(&data1);
+    mac2.update(&data2);
+    let tag1 = mac1.finalize();
+    let tag2 = mac2.finalize();
+
+    assert!(tag1 != tag2);
+    assert!(tag1 == expected(&data1));
+    assert!(tag2 == expected(&data2));
+}
+
+#[test]
+fn clone_hmac() {
+    check_clone::<Hmac<Sha256>>(b"my secret and secure key");
+    check_clone::<SimpleHmac<Sha256>>(b"my secret and secure key");
+}
+
+#[test]
+fn clone_cmac() {
+    check_clone::<Cmac<Aes128>>(&[0x42; 16]);
+}
+
+#[test]
+fn clone_pmac() {
+    check_clone::<Pmac<Aes128>>(&[0x42; 16]);
+}
+
+#[test]
+fn clone_cbc_mac() {
+    check_clone::<CbcMac<Des>>(&[0x42; 8]);
+}
+
+#[test]
+fn clone_retail_mac() {
+    check_clone::<RetailMac<Des>>(&[0x42; 16]);
+}
+
+#[test]
+fn clone_belt_mac() {
+    check_clone::<BeltMac>(&[0x42; 32]);
+}
@@ -0,0 +1,52 @@
+use aes::Aes128;
+use cmac::Cmac;
+use hmac::Hmac;
+use mac_utils::{Combined, KeyInit, Mac};
+use sha2::Sha256;
+
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+fn new_combined() -> Combined<Hmac<Sha256>, Cmac<Aes128>> {
+    let a = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+    let b = Cmac::<Aes128>::new_from_slice(b"very secret key.").unwrap();
+    Combined::new(a, b)
+}
+
+#[test]
+fn combined_matches_inner_macs() {
+    let (a, b) = new_combined().into_inner();
+    let a_tag = a.chain_update(DATA).finalize().into_bytes();
+    let b_tag = b.chain_update(DATA).finalize().into_bytes();
+
+    let mut mac = new_combined();
+    mac.update(&DATA[..10]);
+    mac.update(&DATA[10..]);
+    let tag = mac.finalize().into_bytes();
+
+    assert_eq!(tag.len(), 48);
+    assert_eq!(tag[..32], a_tag[..]);
+    assert_eq!(tag[32..], b_tag[..]);
+}
+
+#[test]
+fn combined_verify() {
+    let mac = new_combined().chain_update(DATA);
+    let tag = mac.clone().finalize().into_bytes();
+    mac.clone().verify(&tag).unwrap();
+
+    // corruption of either half must be detected
+    for i in [0, 31, 32, 47] {
+        let mut bad = tag;
+        bad[i] ^= 1;
+        assert!(mac.clone().verify(&bad).is_err());
+    }
+}
+
+#[test]
+fn combined_reset() {
+    let mut mac = new_combined();
+    mac.update(DATA);
+    let tag = mac.finalize_reset();
+    mac.update(DATA);
+    assert_eq!(mac.finalize(), tag);
+}
@@ -0,0 +1,135 @@
+use digest::{consts::U1, FixedOutput, MacMarker, Output, OutputSizeUser, Update};
+use hmac::Hmac;
+use mac_utils::{Committing, KeyInit, Mac};
+use sha2::Sha256;
+
+/// Insecure MAC whose tags can be trivially made to collide across keys.
+#[derive(Clone)]
+struct XorMac {
+    acc: u8,
+}
+
+impl XorMac {
+    fn new(key: u8) -> Self {
+        Self { acc: key }
+    }
+}
+
+impl OutputSizeUser for XorMac {
+    type OutputSize = U1;
+}
+
+impl MacMarker for XorMac {}
+
+impl Update for XorMac {
+    fn update(&mut self, data: &[u8]) {
+        self.acc = data.iter().fold(self.acc, |a, b| a ^ b);
+    }
+}
+
+impl FixedOutput for XorMac {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out[0] = self.acc;
+    }
+}
+
+#[test]
+fn committing_output() {
+    let key = b"my secret and secure key";
+    let msg = b"input message";
+    let mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+    let tag = Committing::new(mac.clone())
+        .chain_update(msg)
+        .finalize()
+        .into_bytes();
+
+    let inner_tag = mac
+        .clone()
+        .chain_update([0x00])
+        .chain_update(msg)
+        .finalize()
+        .into_bytes();
+    let commitment = mac
+        .chain_update([0x01])
+        .chain_update(b"RustCrypto MAC key commitment")
+        .finalize()
+        .into_bytes();
+    assert_eq!(tag[..32], inner_tag[..]);
+    assert_eq!(tag[32..], commitment[..]);
+}
+
+#[test]
+fn committing_binds_key() {
+    let mac1 = Committing::new(Hmac::<Sha256>::new_from_slice(b"first key").unwrap());
+    let mac2 = Committing::new(Hmac::<Sha256>::new_from_slice(b"second key").unwrap());
+    assert_ne!(mac1.commitment(), mac2.commitment());
+
+    let tag = mac1
+        .clone()
+        .chain_update(b"message")
+        .finalize()
+        .into_bytes();
+    mac1.chain_update(b"message").verify(&tag).unwrap();
+    assert!(mac2.chain_update(b"message").verify(&tag).is_err());
+}
+
+#[test]
+fn committing_detects_tag_collision() {
+    // Messages with colliding tags under two different keys
+    let (key1, msg1) = (0x01, [0x10]);
+    let (key2, msg2) = (0x02, [0x13]);
+    let tag1 = XorMac::new(key1).chain_update(msg1).finalize();
+    let tag2 = XorMac::new(key2).chain_update(msg2).finalize();
+    assert!(tag1 == tag2);
+
+    let tag = Committing::new(XorMac::new(key1))
+        .chain_update(msg1)
+        .finalize()
+        .into_bytes();
+    assert_eq!(tag[0], 0x11);
+    let mac = Committing::new(XorMac::new(key2)).chain_update(msg2);
+    assert!(mac.verify(&tag).is_err());
+}
+
+#[test]
+fn committing_rejects_commitment_as_tag() {
+    let mac = Committing::new(Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap());
+    let tag = mac
+        .clone()
+        .chain_update(b"input message")
+        .finalize()
+        .into_bytes();
+
+    // `C || C` must not be a valid tag for the commitment label
+    let c = &tag[32..];
+    let mut forged = [0u8; 64];
+    forged[..32].copy_from_slice(c);
+    forged[32..].copy_from_slice(c);
+    let label = b"RustCrypto MAC key commitment";
+    assert!(mac
+        .clone()
+        .chain_update(label)
+        .verify_slice(&forged)
+        .is_err());
+    let mut prefixed_label = vec![0x01];
+    prefixed_label.extend_from_slice(label);
+    assert!(mac
+        .chain_update(&prefixed_label)
+        .verify_slice(&forged)
+        .is_err());
+}
+
+#[test]
+fn committing_reset() {
+    let mac = Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap();
+    let mut mac = Committing::new(mac);
+    let expected = mac.clone().chain_update(b"input message").finalize();
+
+    Mac::update(&mut mac, b"input message");
+    assert_eq!(mac.finalize_reset(), expected);
+    Mac::update(&mut mac, b"input message");
+    assert_eq!(mac.clone().finalize(), expected);
+    Mac::reset(&mut mac);
+    Mac::update(&mut mac, b"input message");
+    assert_eq!(mac.finalize(), expected);
+}
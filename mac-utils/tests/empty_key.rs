@@ -0,0 +1,34 @@
+//! Behavior of MACs initialized with an empty key, as documented in the
+//! "Key length" section of each crate.
+use aes::Aes128;
+use belt_mac::BeltMac;
+use cbc_mac::CbcMac;
+use cmac::Cmac;
+use des::Des;
+use hmac::{Hmac, SimpleHmac};
+use mac_utils::{KeyInit, Mac};
+use pmac::Pmac;
+use retail_mac::RetailMac;
+use sha2::Sha256;
+
+#[test]
+fn empty_key_hmac() {
+    let tag = Hmac::<Sha256>::new_from_slice(&[])
+        .unwrap()
+        .chain_update(b"input message")
+        .finalize();
+    // Empty key is equivalent to the all-zero key of block size
+    let mac = SimpleHmac::<Sha256>::new_from_slice(&[0; 64]).unwrap();
+    mac.chain_update(b"input message")
+        .verify(&tag.into_bytes())
+        .unwrap();
+}
+
+#[test]
+fn empty_key_block_cipher_macs() {
+    assert!(Cmac::<Aes128>::new_from_slice(&[]).is_err());
+    assert!(Pmac::<Aes128>::new_from_slice(&[]).is_err());
+    assert!(CbcMac::<Des>::new_from_slice(&[]).is_err());
+    assert!(RetailMac::<Des>::new_from_slice(&[]).is_err());
+    assert!(<BeltMac>::new_from_slice(&[]).is_err());
+}
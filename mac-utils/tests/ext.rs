@@ -0,0 +1,147 @@
+use aes::Aes128;
+use belt_mac::BeltMac;
+use cbc_mac::CbcMac;
+use cmac::{digest::consts::U8, Cmac, CmacCore};
+use des::Des;
+use hmac::{Hmac, SimpleHmac};
+use mac_utils::{digest::FixedOutput, KeyInit, Mac, MacExt, Truncated};
+use pmac::Pmac;
+use retail_mac::RetailMac;
+use sha2::Sha256;
+
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+fn check_oneshot<M: Mac + KeyInit + FixedOutput + Clone>(key: &[u8]) {
+    let tag = M::mac_oneshot(key, DATA).unwrap();
+    let mac = M::new_from_slice(key).unwrap().chain_update(DATA);
+    mac.clone().verify(&tag).unwrap();
+    check_finalize_into_slice(mac.clone());
+    #[cfg(feature = "alloc")]
+    check_finalize_append(mac.clone());
+    check_verify_and_get(mac);
+}
+
+fn check_finalize_into_slice<M: Mac + FixedOutput + Clone>(mac: M) {
+    let expected = mac.clone().finalize().into_bytes();
+    let n = expected.len();
+
+    // tag occupies `frame[5..5 + n]`, the rest of the frame is untouched
+    let mut frame = [0xAAu8; 128];
+    mac.clone()
+        .finalize_into_slice(&mut frame[5..5 + n])
+        .unwrap();
+    assert_eq!(frame[5..5 + n], expected[..]);
+    assert!(frame[..5].iter().all(|&b| b == 0xAA));
+    assert!(frame[5 + n..].iter().all(|&b| b == 0xAA));
+
+    // longer buffers are accepted, only the first `n` bytes are written
+    let mut frame = [0xAAu8; 128];
+    mac.clone().finalize_into_slice(&mut frame[1..]).unwrap();
+    assert_eq!(frame[1..1 + n], expected[..]);
+    assert!(frame[1 + n..].iter().all(|&b| b == 0xAA));
+
+    let mut short = [0u8; 128];
+    assert!(mac.finalize_into_slice(&mut short[..n - 1]).is_err());
+}
+
+#[cfg(feature = "alloc")]
+fn check_finalize_append<M: Mac + Clone>(mac: M) {
+    let expected = mac.clone().finalize().into_bytes();
+
+    let frame = mac.clone().finalize_append(DATA.to_vec());
+    assert_eq!(frame[..DATA.len()], *DATA);
+    assert_eq!(frame[DATA.len()..], expected[..]);
+
+    assert_eq!(mac.finalize_append(Vec::new()), expected.to_vec());
+}
+
+fn check_verify_and_get<M: Mac + Clone>(mac: M) {
+    let expected = mac.clone().finalize().into_bytes();
+
+    let (tag, res) = mac.clone().verify_and_get(&expected);
+    assert_eq!(tag, expected);
+    assert!(res.is_ok());
+
+    let mut bad = expected.clone();
+    let n = bad.len();
+    bad[n - 1] ^= 1;
+    let (tag, res) = mac.clone().verify_and_get(&bad);
+    assert_eq!(tag, expected);
+    assert!(res.is_err());
+
+    let (tag, res) = mac.verify_and_get(&expected[..n - 1]);
+    assert_eq!(tag, expected);
+    assert!(res.is_err());
+}
+
+#[test]
+fn oneshot_hmac() {
+    check_oneshot::<Hmac<Sha256>>(b"key");
+    check_oneshot::<SimpleHmac<Sha256>>(b"key");
+}
+
+#[test]
+fn oneshot_cmac() {
+    check_oneshot::<Cmac<Aes128>>(&[0x42; 16]);
+}
+
+#[test]
+fn oneshot_pmac() {
+    check_oneshot::<Pmac<Aes128>>(&[0x42; 16]);
+}
+
+#[test]
+fn oneshot_cbc_mac() {
+    check_oneshot::<CbcMac<Des>>(&[0x42; 8]);
+}
+
+#[test]
+fn oneshot_retail_mac() {
+    check_oneshot::<RetailMac<Des>>(&[0x42; 16]);
+}
+
+#[test]
+fn oneshot_belt_mac() {
+    check_oneshot::<BeltMac>(&[0x42; 32]);
+}
+
+#[test]
+fn oneshot_invalid_key() {
+    assert!(Cmac::<Aes128>::mac_oneshot(&[0x42; 15], DATA).is_err());
+}
+
+#[test]
+fn verify_slice_labeled() {
+    let mac = Cmac::<Aes128>::new_from_slice(&[0x42; 16])
+        .unwrap()
+        .chain_update(DATA);
+    let tag = mac.clone().finalize().into_bytes();
+    mac.clone()
+        .verify_slice_labeled(&tag, "record-mac")
+        .unwrap();
+
+    let err = mac
+        .verify_slice_labeled(&tag[..8], "record-mac")
+        .unwrap_err();
+    assert_eq!(err.label(), "record-mac");
+    assert_eq!(err.to_string(), "record-mac: MAC tag mismatch");
+}
+
+#[test]
+fn finalize_into_slice_truncated() {
+    let mac = Truncated::<CmacCore<Aes128>, U8>::new_from_slice(&[0x42; 16])
+        .unwrap()
+        .chain_update(DATA);
+    let full = Cmac::<Aes128>::new_from_slice(&[0x42; 16])
+        .unwrap()
+        .chain_update(DATA)
+        .finalize()
+        .into_bytes();
+
+    let mut frame = [0u8; 16];
+    mac.clone().finalize_into_slice(&mut frame).unwrap();
+    assert_eq!(frame[..8], full[..8]);
+    assert_eq!(frame[8..], [0u8; 8]);
+
+    assert!(mac.finalize_into_slice(&mut frame[..7]).is_err());
+}
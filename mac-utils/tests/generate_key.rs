@@ -0,0 +1,97 @@
+#![cfg(feature = "rand_core")]
+
+use aes::{Aes128, Aes256};
+use belt_mac::BeltMac;
+use cbc_mac::CbcMac;
+use cmac::Cmac;
+use des::Des;
+use hmac::{Hmac, SimpleHmac};
+use mac_utils::{
+    digest::{
+        crypto_common::KeySizeUser,
+        rand_core::{self, CryptoRng, RngCore},
+        typenum::Unsigned,
+    },
+    KeyInit, Mac,
+};
+use pmac::Pmac;
+use retail_mac::RetailMac;
+use sha2::{Sha256, Sha512};
+
+/// Deterministic xorshift generator used only for testing.
+struct TestRng(u64);
+
+impl RngCore for TestRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let v = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&v[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for TestRng {}
+
+fn check_generate_key<M: Mac + KeyInit>(key_len: usize) {
+    let mut rng = TestRng(0x2545_F491_4F6C_DD1D);
+    let key1 = M::generate_key_with_rng(&mut rng).unwrap();
+    let key2 = M::generate_key_with_rng(&mut rng).unwrap();
+    assert_eq!(key1.len(), key_len);
+    assert_eq!(key1.len(), <M as KeySizeUser>::KeySize::USIZE);
+    assert_ne!(key1, key2);
+
+    // generated key constructs a working MAC
+    let tag = M::new(&key1)
+        .chain_update(b"input message")
+        .finalize()
+        .into_bytes();
+    M::new_from_slice(&key1)
+        .unwrap()
+        .chain_update(b"input message")
+        .verify_slice(&tag)
+        .unwrap();
+    M::new(&key2)
+        .chain_update(b"input message")
+        .verify_slice(&tag)
+        .unwrap_err();
+}
+
+#[test]
+fn generate_key_hmac() {
+    // HMAC keys are generated with length equal to the hash block size
+    check_generate_key::<Hmac<Sha256>>(64);
+    check_generate_key::<Hmac<Sha512>>(128);
+    check_generate_key::<SimpleHmac<Sha256>>(64);
+}
+
+#[test]
+fn generate_key_block_cipher_macs() {
+    check_generate_key::<Cmac<Aes128>>(16);
+    check_generate_key::<Cmac<Aes256>>(32);
+    check_generate_key::<Pmac<Aes128>>(16);
+    check_generate_key::<CbcMac<Aes128>>(16);
+    check_generate_key::<BeltMac>(32);
+}
+
+#[test]
+fn generate_key_retail_mac() {
+    // Retail MAC uses a double-length key
+    check_generate_key::<RetailMac<Des>>(16);
+    check_generate_key::<RetailMac<Aes128>>(32);
+}
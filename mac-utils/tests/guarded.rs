@@ -0,0 +1,31 @@
+use aes::Aes128;
+use cmac::Cmac;
+use mac_utils::{GuardedVerifier, KeyInit, Mac};
+
+#[test]
+fn guarded_verifier() {
+    let mac = Cmac::<Aes128>::new_from_slice(b"very secret key.")
+        .unwrap()
+        .chain_update(b"input message");
+    let tag = mac.clone().finalize().into_bytes();
+    let bad = [0u8; 16];
+
+    let mut calls = Vec::new();
+    let mut verifier = GuardedVerifier::new(3, |n| calls.push(n));
+
+    for i in 1..=2 {
+        assert!(verifier.verify(mac.clone(), &bad).is_err());
+        assert_eq!(verifier.failures(), i);
+    }
+    // success resets the counter
+    verifier.verify(mac.clone(), &tag).unwrap();
+    assert_eq!(verifier.failures(), 0);
+
+    for _ in 0..4 {
+        assert!(verifier.verify(mac.clone(), &bad).is_err());
+    }
+    verifier.verify(mac.clone(), &tag).unwrap();
+    assert!(verifier.verify(mac, &bad).is_err());
+
+    assert_eq!(calls, [3, 4]);
+}
@@ -0,0 +1,67 @@
+//! HKDF instantiated with MACs other than HMAC.
+use aes::Aes128;
+use cmac::Cmac;
+use hex_literal::hex;
+use hmac::{hkdf, Hmac};
+use mac_utils::{KeyInit, Mac};
+use sha2::Sha256;
+
+type CmacAes128 = Cmac<Aes128>;
+
+const IKM: [u8; 22] = [0x0b; 22];
+const INFO: [u8; 10] = hex!("f0f1f2f3f4f5f6f7f8f9");
+
+#[test]
+fn hkdf_hmac_sha256_rfc5869() {
+    let salt = hex!("000102030405060708090a0b0c");
+    let prk = hkdf::extract::<Hmac<Sha256>>(&salt, &IKM).unwrap();
+    let mut okm = [0u8; 42];
+    hkdf::expand::<Hmac<Sha256>>(&prk, &INFO, &mut okm).unwrap();
+    assert_eq!(
+        okm,
+        hex!(
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf"
+            "34007208d5b887185865"
+        ),
+    );
+}
+
+#[test]
+fn hkdf_cmac_aes128() {
+    let salt = hex!("000102030405060708090a0b0c0d0e0f");
+    let prk = hkdf::extract::<CmacAes128>(&salt, &IKM).unwrap();
+    assert_eq!(prk[..], hex!("6cfc3580c773d8bf9a3ed2adbdedfec6"));
+
+    // output length is not a multiple of the CMAC tag size
+    let mut okm = [0u8; 42];
+    hkdf::expand::<CmacAes128>(&prk, &INFO, &mut okm).unwrap();
+    assert_eq!(
+        okm,
+        hex!(
+            "7ca0e15545987da2c001a68c9725c22a41f3aaca42b4234f1e4aec061ec6e9ad"
+            "554a0b125041c8101084"
+        ),
+    );
+
+    // first block is a plain CMAC over `info || 0x01`
+    let t1 = CmacAes128::new_from_slice(&prk)
+        .unwrap()
+        .chain_update(INFO)
+        .chain_update([1])
+        .finalize()
+        .into_bytes();
+    assert_eq!(okm[..16], t1[..]);
+}
+
+#[test]
+fn hkdf_cmac_aes128_errors() {
+    // CMAC does not accept the empty default salt
+    assert!(hkdf::extract::<CmacAes128>(&[], &IKM).is_err());
+
+    let prk = [0u8; 16];
+    let mut okm = [0u8; 255 * 16];
+    assert!(hkdf::expand::<CmacAes128>(&prk, &INFO, &mut okm).is_ok());
+    let mut okm = [0u8; 255 * 16 + 1];
+    assert!(hkdf::expand::<CmacAes128>(&prk, &INFO, &mut okm).is_err());
+    assert!(hkdf::expand::<CmacAes128>(&prk[..15], &INFO, &mut okm[..16]).is_err());
+}
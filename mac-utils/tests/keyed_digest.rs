@@ -0,0 +1,77 @@
+use aes::Aes128;
+use cmac::Cmac;
+use digest::{FixedOutput, FixedOutputReset, Update};
+use hmac::Hmac;
+use mac_utils::{KeyInit, KeyedAsDigest, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const KEY: &[u8] = b"my secret and secure key";
+
+fn hmac(data: &[u8]) -> [u8; 32] {
+    HmacSha256::new_from_slice(KEY)
+        .unwrap()
+        .chain_update(data)
+        .finalize()
+        .into_bytes()
+        .into()
+}
+
+/// Hash-generic function which hashes each part separately.
+fn hash_each<D: Update + FixedOutputReset>(d: &mut D, parts: &[&[u8]]) -> [[u8; 32]; 2] {
+    let mut res = [[0u8; 32]; 2];
+    for (out, part) in res.iter_mut().zip(parts) {
+        d.update(part);
+        out.copy_from_slice(&d.finalize_fixed_reset());
+    }
+    res
+}
+
+/// Hash-generic function consuming the hasher.
+fn hash_once<D: Update + FixedOutput>(mut d: D, data: &[u8]) -> [u8; 32] {
+    d.update(data);
+    let mut out = [0u8; 32];
+    out.copy_from_slice(d.finalize_fixed().as_slice());
+    out
+}
+
+#[test]
+fn keyed_as_digest() {
+    let d = KeyedAsDigest::<HmacSha256>::new_from_slice(KEY).unwrap();
+    assert_eq!(
+        hash_once(d.clone(), b"input message"),
+        hmac(b"input message")
+    );
+
+    // `reset` re-keys the MAC
+    let mut d2 = d.clone();
+    let res = hash_each(&mut d2, &[b"first", b"second"]);
+    assert_eq!(res, [hmac(b"first"), hmac(b"second")]);
+
+    d2.update(b"garbage");
+    digest::Reset::reset(&mut d2);
+    assert_eq!(hash_once(d2, b"input message"), hmac(b"input message"));
+
+    let d3 = KeyedAsDigest::new(HmacSha256::new_from_slice(KEY).unwrap());
+    assert_eq!(hash_once(d3, b""), hmac(b""));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn keyed_as_dyn_digest() {
+    use digest::DynDigest;
+
+    let d = KeyedAsDigest::<HmacSha256>::new_from_slice(KEY).unwrap();
+    let mut d: Box<dyn DynDigest> = Box::new(d);
+    assert_eq!(d.output_size(), 32);
+    d.update(b"input message");
+    assert_eq!(d.finalize_reset()[..], hmac(b"input message"));
+    d.update(b"second");
+    assert_eq!(d.finalize()[..], hmac(b"second"));
+}
+
+#[test]
+fn keyed_as_digest_invalid_key() {
+    assert!(KeyedAsDigest::<Cmac<Aes128>>::new_from_slice(&[0u8; 15]).is_err());
+}
@@ -0,0 +1,79 @@
+use aes::Aes128;
+use cmac::Cmac;
+use hmac::Hmac;
+use mac_utils::{KeyInit, LimitExceeded, Limited, Mac};
+use sha2::Sha256;
+
+#[test]
+fn limited_under_limit() {
+    let key = b"my secret and secure key";
+    let mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+    let mut limited = Limited::new(mac, 100);
+    limited.try_update(&[0x42; 60]).unwrap();
+    limited.try_update(&[0x42; 40]).unwrap();
+    assert_eq!(limited.processed(), 100);
+    assert_eq!(limited.remaining(), 0);
+    limited.try_update(&[]).unwrap();
+
+    let expected = Hmac::<Sha256>::new_from_slice(key)
+        .unwrap()
+        .chain_update([0x42; 100])
+        .finalize();
+    assert_eq!(limited.finalize(), expected.into_bytes().into());
+}
+
+#[test]
+fn limited_over_limit() {
+    let key = b"very secret key.";
+    let mac = Cmac::<Aes128>::new_from_slice(key).unwrap();
+    let mut limited = Limited::new(mac, 10);
+    limited.try_update(b"0123").unwrap();
+    assert_eq!(limited.try_update(b"0123456"), Err(LimitExceeded));
+    // rejected data must not be processed
+    assert_eq!(limited.processed(), 4);
+    limited.try_update(b"456789").unwrap();
+
+    let expected = Cmac::<Aes128>::new_from_slice(key)
+        .unwrap()
+        .chain_update(b"0123456789")
+        .finalize()
+        .into_bytes();
+    limited.verify(&expected).unwrap();
+}
+
+#[test]
+fn limited_reset() {
+    let mac = Cmac::<Aes128>::new_from_slice(b"very secret key.").unwrap();
+    let mut limited = Limited::new(mac, 10);
+    limited.try_update(b"0123456789").unwrap();
+    let tag = limited.finalize_reset();
+    assert_eq!(limited.remaining(), 10);
+    limited.try_update(b"0123456789").unwrap();
+    assert_eq!(limited.finalize(), tag);
+}
+
+#[test]
+fn limited_rejected_data_is_not_authenticated() {
+    let key = b"very secret key.";
+    let mac = Cmac::<Aes128>::new_from_slice(key).unwrap();
+    let mut limited = Limited::new(mac, 10);
+    limited.try_update(b"0123").unwrap();
+    assert!(limited.try_update(b"0123456789A").is_err());
+
+    let tag = Cmac::<Aes128>::new_from_slice(key)
+        .unwrap()
+        .chain_update(b"01230123456789A")
+        .finalize()
+        .into_bytes();
+    assert!(limited.clone().verify_slice(&tag).is_err());
+
+    limited.reset();
+    assert_eq!(limited.processed(), 0);
+    limited.try_update(b"0123").unwrap();
+    let tag = Cmac::<Aes128>::new_from_slice(key)
+        .unwrap()
+        .chain_update(b"0123")
+        .finalize()
+        .into_bytes();
+    limited.verify_slice(&tag).unwrap();
+}
@@ -0,0 +1,49 @@
+use aes::Aes128;
+use cmac::Cmac;
+use hmac::Hmac;
+use mac_utils::{KeyInit, Mac, Nested};
+use sha2::Sha256;
+
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+fn new_inner() -> Cmac<Aes128> {
+    Cmac::<Aes128>::new_from_slice(b"very secret key.").unwrap()
+}
+
+fn new_outer() -> Hmac<Sha256> {
+    Hmac::<Sha256>::new_from_slice(b"my secret and secure key").unwrap()
+}
+
+#[test]
+fn nested_matches_reference() {
+    let inner_tag = new_inner().chain_update(DATA).finalize().into_bytes();
+    let expected = new_outer().chain_update(inner_tag).finalize();
+
+    let mut mac = Nested::new(new_inner(), new_outer());
+    mac.update(&DATA[..7]);
+    mac.update(&DATA[7..]);
+    assert_eq!(mac.clone().finalize().into_bytes(), expected.into_bytes());
+
+    mac.verify(&expected.into_bytes()).unwrap();
+}
+
+#[test]
+fn nested_verify_mismatch() {
+    let mac = Nested::new(new_inner(), new_outer()).chain_update(DATA);
+    let mut tag = mac.clone().finalize().into_bytes();
+    tag[31] ^= 1;
+    assert!(mac.verify(&tag).is_err());
+}
+
+#[test]
+fn nested_reset() {
+    let mut mac = Nested::new(new_inner(), new_outer());
+    mac.update(DATA);
+    let tag = mac.finalize_reset();
+    mac.update(DATA);
+    assert_eq!(mac.clone().finalize(), tag);
+    mac.update(b"garbage");
+    Mac::reset(&mut mac);
+    mac.update(DATA);
+    assert_eq!(mac.finalize(), tag);
+}
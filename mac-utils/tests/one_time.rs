@@ -0,0 +1,69 @@
+use aes::Aes128;
+use cmac::Cmac;
+use hmac::Hmac;
+use mac_utils::{KeyInit, Mac, OneTime};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const KEY: &[u8] = b"one-time key";
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+fn expected() -> [u8; 32] {
+    HmacSha256::new_from_slice(KEY)
+        .unwrap()
+        .chain_update(DATA)
+        .finalize()
+        .into_bytes()
+        .into()
+}
+
+#[test]
+fn one_time_consuming() {
+    let mac = OneTime::<HmacSha256>::new_from_slice(KEY).unwrap();
+    let tag = mac.chain_update(DATA).finalize().into_bytes();
+    assert_eq!(tag[..], expected());
+
+    let mac = OneTime::new(HmacSha256::new_from_slice(KEY).unwrap());
+    mac.chain_update(DATA).verify_slice(&expected()).unwrap();
+}
+
+#[test]
+fn one_time_finalize_once() {
+    let mut mac = OneTime::<HmacSha256>::new_from_slice(KEY).unwrap();
+    assert!(!mac.is_used());
+    mac.update(&DATA[..10]);
+    mac.update(&DATA[10..]);
+    assert_eq!(mac.finalize_once()[..], expected());
+    assert!(mac.is_used());
+}
+
+#[test]
+#[should_panic(expected = "one-time MAC instance reused")]
+fn one_time_finalize_twice() {
+    let mut mac = OneTime::<HmacSha256>::new_from_slice(KEY).unwrap();
+    mac.update(DATA);
+    let _ = mac.finalize_once();
+    let _ = mac.finalize_once();
+}
+
+#[test]
+#[should_panic(expected = "one-time MAC instance reused")]
+fn one_time_update_after_finalize() {
+    let mut mac = OneTime::<HmacSha256>::new_from_slice(KEY).unwrap();
+    let _ = mac.finalize_once();
+    mac.update(DATA);
+}
+
+#[test]
+#[should_panic(expected = "one-time MAC instance reused")]
+fn one_time_finalize_after_finalize_once() {
+    let mut mac = OneTime::<HmacSha256>::new_from_slice(KEY).unwrap();
+    let _ = mac.finalize_once();
+    let _ = mac.finalize();
+}
+
+#[test]
+fn one_time_invalid_key() {
+    assert!(OneTime::<Cmac<Aes128>>::new_from_slice(&[0u8; 15]).is_err());
+}
@@ -0,0 +1,53 @@
+use aes::Aes128;
+use cmac::Cmac;
+use hmac::Hmac;
+use mac_utils::{
+    digest::{FixedOutputReset, MacMarker},
+    KeyInit, Mac, Prefixed,
+};
+use sha2::Sha256;
+
+const HEADER: &[u8] = b"my protocol v1";
+
+fn check<M: MacMarker + FixedOutputReset + KeyInit + Clone>(key: &[u8]) {
+    let expected = |msg: &[u8]| {
+        M::new_from_slice(key)
+            .unwrap()
+            .chain_update(HEADER)
+            .chain_update(msg)
+            .finalize()
+    };
+
+    let mut mac = Prefixed::new(M::new_from_slice(key).unwrap(), HEADER);
+    assert_eq!(mac.header(), &HEADER);
+
+    // initial use
+    Mac::update(&mut mac, b"first message");
+    assert_eq!(
+        mac.finalize_reset().into_bytes(),
+        expected(b"first message").into_bytes(),
+    );
+
+    // after `finalize_reset`
+    Mac::update(&mut mac, b"second message");
+    assert_eq!(
+        mac.clone().finalize().into_bytes(),
+        expected(b"second message").into_bytes(),
+    );
+
+    // after `reset`
+    Mac::reset(&mut mac);
+    Mac::update(&mut mac, b"third message");
+    mac.verify(&expected(b"third message").into_bytes())
+        .unwrap();
+}
+
+#[test]
+fn prefixed_hmac() {
+    check::<Hmac<Sha256>>(b"my secret and secure key");
+}
+
+#[test]
+fn prefixed_cmac() {
+    check::<Cmac<Aes128>>(b"very secret key.");
+}
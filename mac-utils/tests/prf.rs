@@ -0,0 +1,47 @@
+use aes::Aes128;
+use cmac::Cmac;
+use digest::typenum::Unsigned;
+use hmac::Hmac;
+use mac_utils::{KeyInit, Mac, Prf, PrfAdapter};
+use pmac::Pmac;
+use sha2::Sha256;
+
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+/// Framework-like function generic over the used PRF
+fn derive<P: Prf>(key: &[u8]) -> Vec<u8> {
+    assert!(key.len() <= P::KeySize::USIZE);
+    P::prf(key, DATA).unwrap().to_vec()
+}
+
+fn reference<M: Mac + KeyInit>(key: &[u8]) -> Vec<u8> {
+    M::new_from_slice(key)
+        .unwrap()
+        .chain_update(DATA)
+        .finalize()
+        .into_bytes()
+        .to_vec()
+}
+
+#[test]
+fn prf_adapter() {
+    let key = [0x42; 16];
+    assert_eq!(
+        derive::<PrfAdapter<Hmac<Sha256>>>(&key),
+        reference::<Hmac<Sha256>>(&key),
+    );
+    assert_eq!(
+        derive::<PrfAdapter<Cmac<Aes128>>>(&key),
+        reference::<Cmac<Aes128>>(&key),
+    );
+    assert_eq!(
+        derive::<PrfAdapter<Pmac<Aes128>>>(&key),
+        reference::<Pmac<Aes128>>(&key),
+    );
+}
+
+#[test]
+fn prf_adapter_invalid_key() {
+    assert!(PrfAdapter::<Cmac<Aes128>>::prf(&[0x42; 15], DATA).is_err());
+    assert!(PrfAdapter::<Hmac<Sha256>>::prf(&[0x42; 15], DATA).is_ok());
+}
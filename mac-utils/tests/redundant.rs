@@ -0,0 +1,82 @@
+use digest::{FixedOutput, MacMarker, Output, OutputSizeUser, Update};
+use hmac::Hmac;
+use mac_utils::{FaultDetected, KeyInit, Mac, Redundant};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const KEY: &[u8] = b"my secret and secure key";
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+/// HMAC which flips a bit of its output to simulate a hardware fault.
+#[derive(Clone)]
+struct FaultyMac {
+    inner: HmacSha256,
+    fault: bool,
+}
+
+impl FaultyMac {
+    fn new(fault: bool) -> Self {
+        let inner = HmacSha256::new_from_slice(KEY).unwrap();
+        Self { inner, fault }
+    }
+}
+
+impl OutputSizeUser for FaultyMac {
+    type OutputSize = <HmacSha256 as OutputSizeUser>::OutputSize;
+}
+
+impl MacMarker for FaultyMac {}
+
+impl Update for FaultyMac {
+    fn update(&mut self, data: &[u8]) {
+        Mac::update(&mut self.inner, data);
+    }
+}
+
+impl FixedOutput for FaultyMac {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        FixedOutput::finalize_into(self.inner, out);
+        if self.fault {
+            out[7] ^= 0x10;
+        }
+    }
+}
+
+#[test]
+fn redundant_tag() {
+    let expected = HmacSha256::new_from_slice(KEY)
+        .unwrap()
+        .chain_update(DATA)
+        .finalize();
+
+    let mut mac = Redundant::new(HmacSha256::new_from_slice(KEY).unwrap());
+    mac.update(&DATA[..5]);
+    mac.update(&DATA[5..]);
+    assert_eq!(mac.clone().finalize(), Ok(expected.clone()));
+
+    let tag = expected.into_bytes();
+    mac.clone().verify(&tag).unwrap();
+
+    let mut bad_tag = tag;
+    bad_tag[0] ^= 1;
+    assert!(mac.verify(&bad_tag).is_err());
+}
+
+#[test]
+fn redundant_fault_detected() {
+    let mut mac = Redundant::from_pair(FaultyMac::new(false), FaultyMac::new(true));
+    mac.update(DATA);
+    assert_eq!(mac.clone().finalize().unwrap_err(), FaultDetected);
+
+    // verification fails closed even for the correct tag
+    let tag = FaultyMac::new(false)
+        .chain_update(DATA)
+        .finalize()
+        .into_bytes();
+    assert!(mac.verify(&tag).is_err());
+
+    let mut mac = Redundant::from_pair(FaultyMac::new(false), FaultyMac::new(false));
+    mac.update(DATA);
+    assert!(mac.finalize().is_ok());
+}
@@ -0,0 +1,64 @@
+use aes::Aes128;
+use cmac::Cmac;
+use mac_utils::{KeyInit, Mac, SeqMac};
+
+const KEY: &[u8] = b"very secret key.";
+
+fn expected(counter: u64, msg: &[u8]) -> [u8; 16] {
+    Cmac::<Aes128>::new_from_slice(KEY)
+        .unwrap()
+        .chain_update(counter.to_be_bytes())
+        .chain_update(msg)
+        .finalize()
+        .into_bytes()
+        .into()
+}
+
+fn new_seq() -> SeqMac<Cmac<Aes128>> {
+    SeqMac::new(Cmac::<Aes128>::new_from_slice(KEY).unwrap())
+}
+
+#[test]
+fn seq_mac_counter() {
+    let mut mac = new_seq();
+    for i in 0..5 {
+        assert_eq!(mac.counter(), i);
+        mac.update(b"message");
+        assert_eq!(mac.finalize().into_bytes()[..], expected(i, b"message"));
+    }
+    assert_eq!(mac.counter(), 5);
+
+    let mut mac = SeqMac::with_counter(Cmac::<Aes128>::new_from_slice(KEY).unwrap(), 42);
+    mac.update(b"message");
+    assert_eq!(mac.finalize().into_bytes()[..], expected(42, b"message"));
+}
+
+#[test]
+fn seq_mac_reordering() {
+    let mut sender = new_seq();
+    let tags: Vec<_> = [b"first", b"secnd", b"third"]
+        .iter()
+        .map(|msg| {
+            sender.update(*msg);
+            sender.finalize().into_bytes()
+        })
+        .collect();
+
+    let mut receiver = new_seq();
+    // replayed and reordered messages are rejected without advancing counter
+    receiver.update(b"secnd");
+    assert!(receiver.verify(&tags[1]).is_err());
+    assert_eq!(receiver.counter(), 0);
+
+    receiver.update(b"first");
+    receiver.verify(&tags[0]).unwrap();
+    receiver.update(b"first");
+    assert!(receiver.verify(&tags[0]).is_err());
+    assert_eq!(receiver.counter(), 1);
+
+    receiver.update(b"secnd");
+    receiver.verify(&tags[1]).unwrap();
+    receiver.update(b"third");
+    receiver.verify(&tags[2]).unwrap();
+    assert_eq!(receiver.counter(), 3);
+}
@@ -0,0 +1,61 @@
+use aes::Aes128;
+use cmac::Cmac;
+use hmac::Hmac;
+use mac_utils::{KeyInit, ResettableMac, StreamVerifier};
+use sha2::Sha256;
+
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+fn check<M: ResettableMac + KeyInit + Clone>(key: &[u8]) {
+    let mac = M::new_from_slice(key).unwrap();
+    let tag = mac.clone().chain_update(DATA).finalize().into_bytes();
+    let mut verifier = StreamVerifier::new(mac);
+
+    for msg_chunk in [1, 3, 16, DATA.len()] {
+        for tag_chunk in [1, 5, tag.len()] {
+            DATA.chunks(msg_chunk).for_each(|c| verifier.update(c));
+            tag.chunks(tag_chunk).for_each(|c| verifier.update_tag(c));
+            verifier.finish().unwrap();
+        }
+    }
+
+    // wrong tag
+    let mut bad = tag.clone();
+    bad[3] ^= 1;
+    verifier.update(DATA);
+    verifier.update_tag(&bad);
+    assert!(verifier.finish().is_err());
+
+    // incomplete tag
+    verifier.update(DATA);
+    verifier.update_tag(&tag[..tag.len() - 1]);
+    assert!(verifier.finish().is_err());
+
+    // too long tag
+    verifier.update(DATA);
+    verifier.update_tag(&tag);
+    verifier.update_tag(&[0]);
+    assert!(verifier.finish().is_err());
+
+    // message data after tag
+    verifier.update(&DATA[..10]);
+    verifier.update_tag(&tag[..5]);
+    verifier.update(&DATA[10..]);
+    verifier.update_tag(&tag[5..]);
+    assert!(verifier.finish().is_err());
+
+    // the verifier is usable after failures
+    verifier.update(DATA);
+    verifier.update_tag(&tag);
+    verifier.finish().unwrap();
+}
+
+#[test]
+fn stream_verifier_hmac() {
+    check::<Hmac<Sha256>>(b"my secret and secure key");
+}
+
+#[test]
+fn stream_verifier_cmac() {
+    check::<Cmac<Aes128>>(b"very secret key.");
+}
@@ -0,0 +1,38 @@
+use aes::Aes128;
+use cmac::Cmac;
+use hmac::Hmac;
+use mac_utils::{KeyInit, Mac, MacExt};
+use pmac::Pmac;
+use sha2::{Sha256, Sha512};
+
+#[test]
+fn expected_tag_len() {
+    assert_eq!(Hmac::<Sha256>::expected_tag_len(), 32);
+    assert_eq!(Hmac::<Sha512>::expected_tag_len(), 64);
+    assert_eq!(Cmac::<Aes128>::expected_tag_len(), 16);
+    assert_eq!(Pmac::<Aes128>::expected_tag_len(), 16);
+}
+
+#[test]
+fn early_reject() {
+    type M = Cmac<Aes128>;
+    let key = b"very secret key.";
+    let msg = [0x42u8; 1000];
+    let tag = M::new_from_slice(key)
+        .unwrap()
+        .chain_update(msg)
+        .finalize()
+        .into_bytes();
+
+    for len in [0, 1, 8, 15, 17, 32, 1000] {
+        let bad = vec![0u8; len];
+        assert!(M::check_tag_len(&bad).is_err());
+        // the pre-check agrees with the full verification
+        let mac = M::new_from_slice(key).unwrap().chain_update(msg);
+        assert!(mac.verify_slice(&bad).is_err());
+    }
+
+    M::check_tag_len(&tag).unwrap();
+    let mac = M::new_from_slice(key).unwrap().chain_update(msg);
+    mac.verify_slice(&tag).unwrap();
+}
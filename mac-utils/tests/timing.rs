@@ -0,0 +1,126 @@
+//! dudect-style statistical timing tests for tag verification.
+//!
+//! The tests measure `verify_slice` with matching tags and with tags which
+//! differ in the first byte, and use Welch's t-test to check whether the
+//! two timing distributions are distinguishable. A regression replacing
+//! constant-time comparison with `==` results in large `t` values.
+//!
+//! Wall-clock measurements are noisy, so the tests are ignored by default
+//! and should be run manually on an otherwise idle machine:
+//!
+//! ```sh
+//! cargo test --release --features timing-tests --test timing -- --ignored
+//! ```
+#![cfg(feature = "timing-tests")]
+
+use aes::Aes128;
+use cmac::Cmac;
+use hmac::Hmac;
+use mac_utils::{KeyInit, Mac};
+use sha2::Sha256;
+use std::{hint::black_box, time::Instant};
+
+/// Number of measurements per test.
+const SAMPLES: usize = 1_000_000;
+/// Threshold for `|t|` above which timings are considered leaking.
+///
+/// dudect uses 4.5 for "probably leaking" and 10 for "definitely leaking".
+/// The higher value is used to keep false positives on noisy machines low.
+const T_THRESHOLD: f64 = 10.0;
+
+/// Simple xorshift generator used for random interleaving of the classes.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next_bool(&mut self) -> bool {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 & 1 == 1
+    }
+}
+
+/// Online mean and variance accumulator (Welford's algorithm).
+#[derive(Default)]
+struct Stats {
+    n: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Stats {
+    fn push(&mut self, x: f64) {
+        self.n += 1.0;
+        let delta = x - self.mean;
+        self.mean += delta / self.n;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn var(&self) -> f64 {
+        self.m2 / (self.n - 1.0)
+    }
+}
+
+/// Welch's t statistic for two samples.
+fn welch_t(a: &Stats, b: &Stats) -> f64 {
+    (a.mean - b.mean) / (a.var() / a.n + b.var() / b.n).sqrt()
+}
+
+fn check_verify_timing<M: Mac + KeyInit + Clone>(key: &[u8]) {
+    let mac = M::new_from_slice(key)
+        .unwrap()
+        .chain_update(b"input message");
+    let good = mac.clone().finalize().into_bytes();
+    let mut bad = good.clone();
+    bad[0] ^= 1;
+
+    let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+    let mut timings = Vec::with_capacity(SAMPLES);
+    for _ in 0..SAMPLES {
+        let class = rng.next_bool();
+        let tag = if class { &good[..] } else { &bad[..] };
+        let m = mac.clone();
+
+        let start = Instant::now();
+        let res = black_box(m).verify_slice(black_box(tag));
+        let elapsed = start.elapsed();
+
+        assert_eq!(res.is_ok(), class);
+        timings.push((class, elapsed.as_nanos() as f64));
+    }
+
+    // Crop outliers caused by interrupts and context switches
+    let mut sorted: Vec<f64> = timings.iter().map(|&(_, t)| t).collect();
+    sorted.sort_by(f64::total_cmp);
+    let cutoff = sorted[sorted.len() * 9 / 10];
+
+    let (mut good_stats, mut bad_stats) = (Stats::default(), Stats::default());
+    for (class, t) in timings {
+        if t > cutoff {
+            continue;
+        }
+        if class {
+            good_stats.push(t);
+        } else {
+            bad_stats.push(t);
+        }
+    }
+
+    let t = welch_t(&good_stats, &bad_stats);
+    assert!(
+        t.abs() < T_THRESHOLD,
+        "verification timing depends on tag: t = {t:.2}",
+    );
+}
+
+#[test]
+#[ignore = "statistical timing test, run manually"]
+fn hmac_sha256_verify_timing() {
+    check_verify_timing::<Hmac<Sha256>>(b"my secret and secure key");
+}
+
+#[test]
+#[ignore = "statistical timing test, run manually"]
+fn cmac_aes128_verify_timing() {
+    check_verify_timing::<Cmac<Aes128>>(b"very secret key.");
+}
@@ -0,0 +1,68 @@
+use aes::Aes128;
+use cmac::Cmac;
+use hmac::Hmac;
+use mac_utils::{KeyInit, Mac, MacExt};
+use sha2::Sha256;
+
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+fn check<M: Mac + KeyInit + Clone>(key: &[u8]) {
+    let mac = M::new_from_slice(key).unwrap().chain_update(DATA);
+    let tag = mac.clone().finalize().into_bytes();
+    let n = tag.len();
+
+    for len in [1, 4, 8, 10, 12, n / 2, n] {
+        let t = &tag[..len];
+        mac.clone().verify_truncated_ct(t).unwrap();
+        mac.clone().verify_truncated_left(t).unwrap();
+
+        let mut bad = t.to_vec();
+        bad[len - 1] ^= 1;
+        assert!(mac.clone().verify_truncated_ct(&bad).is_err());
+        bad[len - 1] ^= 1;
+        bad[0] ^= 0x80;
+        assert!(mac.clone().verify_truncated_ct(&bad).is_err());
+    }
+
+    assert!(mac.clone().verify_truncated_ct(&[]).is_err());
+
+    let mut long = tag.to_vec();
+    long.push(0);
+    assert!(mac.clone().verify_truncated_ct(&long).is_err());
+
+    // right-truncated tags must not be accepted
+    assert!(mac.verify_truncated_ct(&tag[n - 8..]).is_err());
+}
+
+#[test]
+fn truncated_ct_hmac() {
+    check::<Hmac<Sha256>>(b"my secret and secure key");
+}
+
+#[test]
+fn truncated_ct_cmac() {
+    check::<Cmac<Aes128>>(b"very secret key.");
+}
+
+#[test]
+fn truncated_core_is_prefix() {
+    use digest::consts::U12;
+    use hmac::HmacCore;
+    use mac_utils::Truncated;
+
+    let key = b"my secret and secure key";
+    let full = Hmac::<Sha256>::new_from_slice(key)
+        .unwrap()
+        .chain_update(DATA)
+        .finalize()
+        .into_bytes();
+
+    let mut mac = Truncated::<HmacCore<Sha256>, U12>::new_from_slice(key).unwrap();
+    mac.update(DATA);
+    let tag = mac.finalize_reset().into_bytes();
+    assert_eq!(tag[..], full[..12]);
+
+    // reset must return the instance to its freshly keyed state
+    mac.update(DATA);
+    mac.verify_slice(&full[..12]).unwrap();
+}
@@ -37,6 +37,12 @@
 //! mac.verify(&tag_bytes).unwrap();
 //! ```
 //!
+//! # Key length
+//! The key is used directly as the block cipher key, so only keys with
+//! length equal to the cipher key size are accepted (e.g. 16 bytes for
+//! AES-128). `new_from_slice` returns [`InvalidLength`][digest::InvalidLength]
+//! for keys of any other length, including empty keys.
+//!
 //! [1]: https://en.wikipedia.org/wiki/PMAC_(cryptography)
 
 #![no_std]
@@ -52,6 +58,7 @@
 extern crate std;
 
 pub use digest::{self, KeyInit, Mac};
+pub use mac_common::MacExt;
 
 use cipher::{BlockCipherEncBackend, BlockCipherEncClosure, BlockCipherEncrypt, ParBlocks};
 use core::fmt;
@@ -71,7 +78,7 @@ use digest::{
 };
 
 #[cfg(feature = "zeroize")]
-use cipher::zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Generic CMAC instance.
 pub type Pmac<C> = CoreWrapper<PmacCore<C, 20>>;
@@ -190,10 +197,20 @@ where
     C: BlockCipherEncrypt + Clone,
     Block<C>: Dbl,
 {
+    /// Reset MAC state to its freshly keyed value. With enabled `zeroize`
+    /// feature the tag and offset state is wiped using `Zeroize`.
     #[inline(always)]
     fn reset(&mut self) {
-        self.state.tag = Default::default();
-        self.state.offset = Default::default();
+        #[cfg(feature = "zeroize")]
+        {
+            self.state.tag.zeroize();
+            self.state.offset.zeroize();
+        }
+        #[cfg(not(feature = "zeroize"))]
+        {
+            self.state.tag = Default::default();
+            self.state.offset = Default::default();
+        }
         self.state.counter = 1;
     }
 }
@@ -365,3 +382,21 @@ fn xor<N: ArraySize>(buf: &mut Array<u8, N>, data: &Array<u8, N>) {
         buf[i] ^= data[i];
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aes::Aes128;
+
+    #[test]
+    fn reset_zeroizes_state() {
+        let mut core = PmacCore::<Aes128, 20>::new_from_slice(&[0x42; 16]).unwrap();
+        core.update_blocks(&[Array::from([0xA5; 16]), Array::from([0x5A; 16])]);
+        assert!(core.state.tag.iter().any(|&b| b != 0));
+        assert!(core.state.offset.iter().any(|&b| b != 0));
+        core.reset();
+        assert!(core.state.tag.iter().all(|&b| b == 0));
+        assert!(core.state.offset.iter().all(|&b| b == 0));
+        assert_eq!(core.state.counter, 1);
+    }
+}
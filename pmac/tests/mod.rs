@@ -1,8 +1,27 @@
 use aes::{Aes128, Aes192, Aes256};
-use digest::new_resettable_mac_test;
+use mac_common::new_resettable_mac_hex_test;
 use pmac::Pmac;
 
 // Test vectors from: http://web.cs.ucdavis.edu/~rogaway/ocb/pmac-test.htm
-new_resettable_mac_test!(pmac_aes128, "aes128", Pmac<Aes128>);
-new_resettable_mac_test!(pmac_aes192, "aes192", Pmac<Aes192>);
-new_resettable_mac_test!(pmac_aes256, "aes256", Pmac<Aes256>);
+new_resettable_mac_hex_test!(pmac_aes128, "aes128", Pmac<Aes128>);
+new_resettable_mac_hex_test!(pmac_aes192, "aes192", Pmac<Aes192>);
+new_resettable_mac_hex_test!(pmac_aes256, "aes256", Pmac<Aes256>);
+
+#[test]
+fn pmac_reset_mid_stream() {
+    use pmac::{KeyInit, Mac};
+
+    let key = [0x42; 16];
+    let msg = b"input message";
+    let expected = Pmac::<Aes128>::new_from_slice(&key)
+        .unwrap()
+        .chain_update(msg)
+        .finalize();
+
+    // process several blocks to advance offset and counter before reset
+    let mut mac = Pmac::<Aes128>::new_from_slice(&key).unwrap();
+    mac.update(&[0xA5; 100]);
+    mac.reset();
+    mac.update(msg);
+    assert_eq!(mac.finalize(), expected);
+}
@@ -28,6 +28,12 @@
 //! mac2.verify_slice(&correct2).unwrap();
 //! ```
 //!
+//! # Key length
+//! Retail MAC uses two cipher keys `K` and `K'`, which are passed
+//! concatenated, so the key length is twice the cipher key size (e.g.
+//! 16 bytes for DES and 32 bytes for AES-128). `new_from_slice` returns
+//! [`InvalidLength`] for keys of any other length, including empty keys.
+//!
 //! [Retail MAC]: https://en.wikipedia.org/wiki/ISO/IEC_9797-1#MAC_algorithm_3
 //! [CBC-MAC]: https://en.wikipedia.org/wiki/CBC-MAC#Security_with_fixed_and_variable-length_messages
 
@@ -41,6 +47,7 @@
 #![warn(missing_docs, rust_2018_idioms)]
 
 pub use digest::{self, Key, KeyInit, Mac};
+pub use mac_common::MacExt;
 
 use cipher::{
     BlockCipherDecrypt, BlockCipherEncBackend, BlockCipherEncClosure, BlockCipherEncrypt,
@@ -62,7 +69,7 @@ use digest::{
 };
 
 #[cfg(feature = "zeroize")]
-use cipher::zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Generic Retail MAC instance.
 pub type RetailMac<C> = CoreWrapper<RetailMacCore<C>>;
@@ -92,13 +99,14 @@ where
     type OutputSize = C::BlockSize;
 }
 
+// Key is a concatenation of keys K and K' of the underlying block cipher
 impl<C> KeySizeUser for RetailMacCore<C>
 where
-    C: BlockCipherEncrypt + BlockCipherDecrypt + Clone,
-    <C as BlockSizeUser>::BlockSize: core::ops::Mul<U2>,
-    <<C as BlockSizeUser>::BlockSize as core::ops::Mul<U2>>::Output: ArraySize,
+    C: BlockCipherEncrypt + BlockCipherDecrypt + Clone + KeySizeUser,
+    <C as KeySizeUser>::KeySize: core::ops::Mul<U2>,
+    <<C as KeySizeUser>::KeySize as core::ops::Mul<U2>>::Output: ArraySize,
 {
-    type KeySize = <<C as BlockSizeUser>::BlockSize as core::ops::Mul<U2>>::Output;
+    type KeySize = <<C as KeySizeUser>::KeySize as core::ops::Mul<U2>>::Output;
 }
 
 impl<C> MacMarker for RetailMacCore<C> where C: BlockCipherEncrypt + BlockCipherDecrypt + Clone {}
@@ -113,8 +121,8 @@ where
 impl<C> KeyInit for RetailMacCore<C>
 where
     C: BlockCipherEncrypt + BlockCipherDecrypt + Clone + KeyInit,
-    <C as BlockSizeUser>::BlockSize: core::ops::Mul<U2>,
-    <<C as BlockSizeUser>::BlockSize as core::ops::Mul<U2>>::Output: ArraySize,
+    <C as KeySizeUser>::KeySize: core::ops::Mul<U2>,
+    <<C as KeySizeUser>::KeySize as core::ops::Mul<U2>>::Output: ArraySize,
 {
     #[inline(always)]
     fn new(key: &Key<Self>) -> Self {
@@ -169,7 +177,12 @@ where
 {
     #[inline(always)]
     fn reset(&mut self) {
-        self.state = Default::default();
+        #[cfg(feature = "zeroize")]
+        self.state.zeroize();
+        #[cfg(not(feature = "zeroize"))]
+        {
+            self.state = Default::default();
+        }
     }
 }
 
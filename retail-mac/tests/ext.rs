@@ -0,0 +1,13 @@
+use des::Des;
+use retail_mac::{KeyInit, Mac, MacExt, RetailMac};
+
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+#[test]
+fn retail_mac_mac_oneshot() {
+    let key = [0x42; 16];
+    let tag = RetailMac::<Des>::mac_oneshot(&key, DATA).unwrap();
+    let mac = RetailMac::<Des>::new_from_slice(&key).unwrap();
+    mac.chain_update(DATA).verify(&tag).unwrap();
+    assert!(RetailMac::<Des>::mac_oneshot(&key[1..], DATA).is_err());
+}
@@ -0,0 +1,28 @@
+use aes::{Aes128, Aes256};
+use des::Des;
+use retail_mac::{
+    digest::{crypto_common::KeySizeUser, typenum::Unsigned},
+    KeyInit, Mac, RetailMac,
+};
+
+#[test]
+fn retail_mac_key_size() {
+    assert_eq!(<RetailMac<Des> as KeySizeUser>::KeySize::USIZE, 16);
+    assert_eq!(<RetailMac<Aes128> as KeySizeUser>::KeySize::USIZE, 32);
+    assert_eq!(<RetailMac<Aes256> as KeySizeUser>::KeySize::USIZE, 64);
+
+    // keys of block ciphers with key size not equal to block size
+    let mac = RetailMac::<Aes256>::new(&Default::default());
+    let tag = mac.chain_update(b"input message").finalize();
+    let mac = RetailMac::<Aes256>::new_from_slice(&[0; 64]).unwrap();
+    mac.chain_update(b"input message")
+        .verify(&tag.into_bytes())
+        .unwrap();
+}
+
+#[test]
+fn retail_mac_invalid_key() {
+    assert!(RetailMac::<Des>::new_from_slice(&[]).is_err());
+    assert!(RetailMac::<Des>::new_from_slice(&[0; 8]).is_err());
+    assert!(RetailMac::<Aes256>::new_from_slice(&[0; 32]).is_err());
+}